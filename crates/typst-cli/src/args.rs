@@ -111,10 +111,30 @@ pub struct CompileCommand {
     #[arg(long = "open", value_name = "VIEWER")]
     pub open: Option<Option<String>>,
 
+    /// Lays out the document into a single image that is tightly sized to its
+    /// content instead of into pages
+    ///
+    /// This is useful for rendering a standalone formula, for example with
+    /// `echo '$ x^2 + 1 $' | typst compile --snippet - formula.svg`. The
+    /// background of the output is transparent.
+    #[arg(long = "snippet")]
+    pub snippet: bool,
+
+    /// Replaces missing image files with placeholders and reports a warning
+    /// instead of failing, which is useful for drafting
+    #[arg(long = "continue-on-missing-assets")]
+    pub continue_on_missing_assets: bool,
+
     /// The PPI (pixels per inch) to use for PNG export
     #[arg(long = "ppi", default_value_t = 144.0)]
     pub ppi: f32,
 
+    /// The maximum number of layout passes before giving up on stabilizing
+    /// counters, states, and queries (defaults to the compiler's built-in
+    /// limit)
+    #[arg(long = "max-layout-passes")]
+    pub max_layout_passes: Option<usize>,
+
     /// Produces performance timings of the compilation process (experimental)
     ///
     /// The resulting JSON file can be loaded into a tracing tool such as
@@ -161,6 +181,12 @@ pub struct QueryCommand {
     #[clap(long = "one", default_value = "false")]
     pub one: bool,
 
+    /// Reports the number of words and characters in the laid-out text
+    /// following each retrieved element (up to the next retrieved element)
+    /// instead of the elements themselves
+    #[clap(long = "count", conflicts_with = "field")]
+    pub count: bool,
+
     /// The format to serialize in
     #[clap(long = "format", default_value = "json")]
     pub format: SerializationFormat,
@@ -405,6 +431,12 @@ pub struct FontArgs {
     /// `--font-path`
     #[arg(long)]
     pub ignore_system_fonts: bool,
+
+    /// Ensures fonts embedded into the Typst binary won't be used, unless
+    /// explicitly included via `--font-path`
+    #[arg(long)]
+    #[cfg_attr(not(feature = "embed-fonts"), clap(hide = true))]
+    pub ignore_embedded_fonts: bool,
 }
 
 /// Which format to use for diagnostics.
@@ -12,10 +12,10 @@ use typst::diag::{
     bail, At, Severity, SourceDiagnostic, SourceResult, StrResult, Warned,
 };
 use typst::foundations::{Datetime, Smart};
-use typst::layout::{Frame, Page, PageRanges};
+use typst::layout::{Abs, Frame, FrameItem, Page, PageRanges};
 use typst::model::Document;
-use typst::syntax::{FileId, Source, Span};
-use typst::WorldExt;
+use typst::syntax::{ast, FileId, Source, Span};
+use typst::{World, WorldExt};
 use typst_pdf::PdfOptions;
 
 use crate::args::{
@@ -82,8 +82,9 @@ impl CompileCommand {
 
 /// Execute a compilation command.
 pub fn compile(mut timer: Timer, mut command: CompileCommand) -> StrResult<()> {
-    let mut world =
-        SystemWorld::new(&command.common).map_err(|err| eco_format!("{err}"))?;
+    let mut world = SystemWorld::new(&command.common)
+        .map_err(|err| eco_format!("{err}"))?
+        .with_placeholders(command.continue_on_missing_assets);
     timer.record(&mut world, |world| compile_once(world, &mut command, false))??;
     Ok(())
 }
@@ -102,7 +103,18 @@ pub fn compile_once(
         Status::Compiling.print(command).unwrap();
     }
 
-    let Warned { output, warnings } = typst::compile(world);
+    let passes = command.max_layout_passes.unwrap_or(typst::DEFAULT_LAYOUT_PASSES);
+    let Warned { output, mut warnings } = if command.snippet {
+        let Warned { output, warnings } =
+            typst::compile_main_snippet_with_passes(world, passes);
+        Warned { output: output.map(snippet_document), warnings }
+    } else {
+        typst::compile_with_passes(world, passes)
+    };
+
+    // Report images that were replaced with placeholders.
+    warnings.extend(missing_asset_warnings(world, output.as_ref().ok()));
+
     let result = output.and_then(|document| export(world, &document, command, watching));
 
     match result {
@@ -151,6 +163,73 @@ pub fn compile_once(
     Ok(())
 }
 
+/// Create warnings for the missing images that were replaced with
+/// placeholders.
+///
+/// The warnings point to the image calls that load the files, as far as they
+/// can be found in the document.
+fn missing_asset_warnings(
+    world: &mut SystemWorld,
+    document: Option<&Document>,
+) -> Vec<SourceDiagnostic> {
+    let missing = world.missing_assets();
+    missing
+        .into_iter()
+        .map(|id| {
+            let span = document
+                .and_then(|document| image_span(world, document, id))
+                .unwrap_or_else(Span::detached);
+            let path = id.vpath().as_rootless_path().display();
+            SourceDiagnostic::warning(span, eco_format!("file not found: {path}"))
+                .with_hint("a placeholder is shown instead")
+        })
+        .collect()
+}
+
+/// Find the span of an image in the document that was loaded from the file.
+fn image_span(world: &dyn World, document: &Document, id: FileId) -> Option<Span> {
+    let mut found = None;
+    for page in &document.pages {
+        page.frame.visit(|_, item| {
+            if let FrameItem::Image(_, _, span) = item {
+                if found.is_none() && loads_file(world, *span, id) {
+                    found = Some(*span);
+                }
+            }
+        });
+    }
+    found
+}
+
+/// Whether the call at the span has a path argument that points to the file.
+fn loads_file(world: &dyn World, span: Span, id: FileId) -> bool {
+    let Some(source) = span.id().and_then(|file| world.source(file).ok()) else {
+        return false;
+    };
+    let Some(node) = source.find(span) else { return false };
+    let Some(call) = node.cast::<ast::FuncCall>() else { return false };
+    let mut args = call.args().items();
+    args.any(|arg| match arg {
+        ast::Arg::Pos(ast::Expr::Str(path)) => {
+            span.resolve_path(&path.get()).is_ok_and(|file| file == id)
+        }
+        _ => false,
+    })
+}
+
+/// Wrap a snippet frame into a document with a single transparent page.
+fn snippet_document(frame: Frame) -> Document {
+    let page = Page {
+        frame,
+        fill: Smart::Custom(None),
+        numbering: None,
+        number: 1,
+        bleed: Abs::zero(),
+        slug: Abs::zero(),
+    };
+    Document { pages: vec![page], ..Document::default() }
+}
+
 /// Export into the target format.
 fn export(
     world: &mut SystemWorld,
@@ -167,12 +246,28 @@ fn export(
             export_image(world, document, command, watching, ImageExportFormat::Svg)
                 .at(Span::detached())
         }
-        OutputFormat::Pdf => export_pdf(document, command),
+        OutputFormat::Pdf => export_pdf(world, document, command, watching),
     }
 }
 
 /// Export to a PDF.
-fn export_pdf(document: &Document, command: &CompileCommand) -> SourceResult<()> {
+fn export_pdf(
+    world: &mut SystemWorld,
+    document: &Document,
+    command: &CompileCommand,
+    watching: bool,
+) -> SourceResult<()> {
+    // If we are watching and the document didn't change, skip the export
+    // unless the file doesn't exist. Otherwise, the whole PDF is re-encoded:
+    // the PDF exporter has no way to reuse objects from a previous run.
+    if watching && world.export_cache().is_document_cached(document) {
+        if let Output::Path(path) = command.output() {
+            if path.exists() {
+                return Ok(());
+            }
+        }
+    }
+
     let options = PdfOptions {
         ident: Smart::Auto,
         timestamp: convert_datetime(
@@ -186,6 +281,11 @@ fn export_pdf(document: &Document, command: &CompileCommand) -> SourceResult<()>
         .write(&buffer)
         .map_err(|err| eco_format!("failed to write PDF file ({err})"))
         .at(Span::detached())?;
+
+    if watching {
+        world.export_cache().cache_document(document);
+    }
+
     Ok(())
 }
 
@@ -362,15 +462,37 @@ impl Output {
 /// of the last rendered frame in each file. If a new frame is inserted, this
 /// will invalidate the rest of the cache, this is deliberate as to decrease the
 /// complexity and memory usage of such a cache.
+///
+/// For formats that produce a single file for the whole document, the hash of
+/// the last successfully exported document is kept instead. Such an export is
+/// either skipped entirely or redone from scratch; pages, images, and fonts
+/// are not reused across exports.
 pub struct ExportCache {
     /// The hashes of last compilation's frames.
     pub cache: RwLock<Vec<u128>>,
+    /// The hash of the last exported document.
+    pub document: RwLock<Option<u128>>,
 }
 
 impl ExportCache {
     /// Creates a new export cache.
     pub fn new() -> Self {
-        Self { cache: RwLock::new(Vec::with_capacity(32)) }
+        Self {
+            cache: RwLock::new(Vec::with_capacity(32)),
+            document: RwLock::new(None),
+        }
+    }
+
+    /// Returns true if the document's pages and metadata are unchanged since
+    /// it was last exported.
+    pub fn is_document_cached(&self, document: &Document) -> bool {
+        *self.document.read() == Some(hash_document(document))
+    }
+
+    /// Remembers the document's hash after it was exported successfully (for
+    /// the next compilation).
+    pub fn cache_document(&self, document: &Document) {
+        *self.document.write() = Some(hash_document(document));
     }
 
     /// Returns true if the entry is cached and appends the new hash to the
@@ -388,6 +510,11 @@ impl ExportCache {
     }
 }
 
+/// Hashes the parts of a document that end up in a single-file export.
+fn hash_document(document: &Document) -> u128 {
+    typst::utils::hash128(&(&document.pages, &document.info))
+}
+
 /// Writes a Makefile rule describing the relationship between the output and
 /// its dependencies to the path specified by the --make-deps argument, if it
 /// was provided.
@@ -606,3 +733,25 @@ impl<'a> codespan_reporting::files::Files<'a> for SystemWorld {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_cache_document() {
+        let cache = ExportCache::new();
+        let mut document = Document::default();
+
+        // Checking does not cache the document, only exporting does.
+        assert!(!cache.is_document_cached(&document));
+        cache.cache_document(&document);
+        assert!(cache.is_document_cached(&document));
+
+        // Changes to the metadata invalidate the cache.
+        document.info.title = Some("Title".into());
+        assert!(!cache.is_document_cached(&document));
+        cache.cache_document(&document);
+        assert!(cache.is_document_cached(&document));
+    }
+}
@@ -27,7 +27,13 @@ pub fn query(command: &QueryCommand) -> HintedStrResult<()> {
         // Retrieve and print query results.
         Ok(document) => {
             let data = retrieve(&world, command, &document)?;
-            let serialized = format(data, command)?;
+            let serialized = if command.count {
+                let starts: Vec<_> = data.iter().filter_map(Content::location).collect();
+                let counts = document.count_text(&starts);
+                serialize(&counts, command.format, command.pretty)?
+            } else {
+                format(data, command)?
+            };
             println!("{serialized}");
             print_diagnostics(&world, &[], &warnings, command.common.diagnostic_format)
                 .map_err(|err| eco_format!("failed to print diagnostics ({err})"))?;
@@ -32,7 +32,9 @@ pub fn watch(mut timer: Timer, mut command: CompileCommand) -> StrResult<()> {
     // Additionally, if any files do not exist, wait until they do.
     let mut world = loop {
         match SystemWorld::new(&command.common) {
-            Ok(world) => break world,
+            Ok(world) => {
+                break world.with_placeholders(command.continue_on_missing_assets)
+            }
             Err(
                 ref err @ (WorldCreationError::InputNotFound(ref path)
                 | WorldCreationError::RootNotFound(ref path)),
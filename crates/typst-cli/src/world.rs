@@ -5,14 +5,17 @@ use std::sync::OnceLock;
 use std::{fmt, fs, io, mem};
 
 use chrono::{DateTime, Datelike, FixedOffset, Local, Utc};
+use comemo::Track;
 use ecow::{eco_format, EcoString};
 use once_cell::sync::Lazy;
 use parking_lot::Mutex;
 use typst::diag::{FileError, FileResult};
-use typst::foundations::{Bytes, Datetime, Dict, IntoValue};
-use typst::syntax::{FileId, Source, VirtualPath};
+use typst::foundations::{Bytes, Datetime, Dict, IntoValue, Smart};
+use typst::layout::{Abs, Frame, FrameItem, Page, Point, Size};
+use typst::syntax::{FileId, Source, Span, VirtualPath};
 use typst::text::{Font, FontBook};
 use typst::utils::LazyHash;
+use typst::visualize::{Image, ImageFormat, VectorFormat};
 use typst::{Library, World};
 use typst_kit::fonts::{FontSlot, Fonts};
 use typst_kit::package::PackageStorage;
@@ -53,6 +56,8 @@ pub struct SystemWorld {
     /// The export cache, used for caching output files in `typst watch`
     /// sessions.
     export_cache: ExportCache,
+    /// Whether missing images are replaced with placeholders.
+    placeholders: bool,
 }
 
 impl SystemWorld {
@@ -116,9 +121,11 @@ impl SystemWorld {
             Library::builder().with_inputs(inputs).build()
         };
 
-        let fonts = Fonts::searcher()
-            .include_system_fonts(!command.font_args.ignore_system_fonts)
-            .search_with(&command.font_args.font_paths);
+        let mut searcher = Fonts::searcher();
+        searcher.include_system_fonts(!command.font_args.ignore_system_fonts);
+        #[cfg(feature = "embed-fonts")]
+        searcher.include_embedded_fonts(!command.font_args.ignore_embedded_fonts);
+        let fonts = searcher.search_with(&command.font_args.font_paths);
 
         let now = match command.creation_timestamp {
             Some(time) => Now::Fixed(time),
@@ -136,6 +143,7 @@ impl SystemWorld {
             package_storage: package::storage(&command.package_storage_args),
             now,
             export_cache: ExportCache::new(),
+            placeholders: false,
         })
     }
 
@@ -185,6 +193,26 @@ impl SystemWorld {
     pub fn export_cache(&self) -> &ExportCache {
         &self.export_cache
     }
+
+    /// Replaces missing image files with placeholders instead of failing.
+    pub fn with_placeholders(mut self, placeholders: bool) -> Self {
+        self.placeholders = placeholders;
+        self
+    }
+
+    /// Return all missing image files that the last compilation replaced with
+    /// placeholders.
+    pub fn missing_assets(&mut self) -> Vec<FileId> {
+        let mut missing: Vec<_> = self
+            .slots
+            .get_mut()
+            .values()
+            .filter(|slot| slot.placeholder.is_some() && slot.missing())
+            .map(|slot| slot.id)
+            .collect();
+        missing.sort_by(|a, b| a.vpath().cmp(b.vpath()));
+        missing
+    }
 }
 
 impl World for SystemWorld {
@@ -205,7 +233,13 @@ impl World for SystemWorld {
     }
 
     fn file(&self, id: FileId) -> FileResult<Bytes> {
-        self.slot(id, |slot| slot.file(&self.root, &self.package_storage))
+        let result = self.slot(id, |slot| slot.file(&self.root, &self.package_storage));
+        if self.placeholders && matches!(result, Err(FileError::NotFound(_))) {
+            if let Some(placeholder) = self.placeholder(id) {
+                return Ok(placeholder);
+            }
+        }
+        result
     }
 
     fn font(&self, index: usize) -> Option<Font> {
@@ -244,6 +278,18 @@ impl SystemWorld {
         let mut map = self.slots.lock();
         f(map.entry(id).or_insert_with(|| FileSlot::new(id)))
     }
+
+    /// Retrieve the placeholder for a missing image file, creating it if
+    /// necessary.
+    fn placeholder(&self, id: FileId) -> Option<Bytes> {
+        if let Some(placeholder) = self.slot(id, |slot| slot.placeholder.clone()) {
+            return Some(placeholder);
+        }
+
+        let placeholder = placeholder(self, id)?;
+        self.slot(id, |slot| slot.placeholder = Some(placeholder.clone()));
+        Some(placeholder)
+    }
 }
 
 /// Holds the processed data for a file ID.
@@ -256,12 +302,19 @@ struct FileSlot {
     source: SlotCell<Source>,
     /// The lazily loaded raw byte buffer.
     file: SlotCell<Bytes>,
+    /// The image that stands in for the file if it is missing.
+    placeholder: Option<Bytes>,
 }
 
 impl FileSlot {
     /// Create a new file slot.
     fn new(id: FileId) -> Self {
-        Self { id, file: SlotCell::new(), source: SlotCell::new() }
+        Self {
+            id,
+            file: SlotCell::new(),
+            source: SlotCell::new(),
+            placeholder: None,
+        }
     }
 
     /// Whether the file was accessed in the ongoing compilation.
@@ -269,6 +322,13 @@ impl FileSlot {
         self.source.accessed() || self.file.accessed()
     }
 
+    /// Whether the file's bytes were requested in the ongoing compilation,
+    /// but the file did not exist.
+    fn missing(&self) -> bool {
+        self.file.accessed()
+            && matches!(self.file.data, Some(Err(FileError::NotFound(_))))
+    }
+
     /// Marks the file as not yet accessed in preparation of the next
     /// compilation.
     fn reset(&mut self) {
@@ -429,6 +489,79 @@ fn read_from_stdin() -> FileResult<Vec<u8>> {
     Ok(buf)
 }
 
+/// Creates an image that stands in for a missing image file.
+///
+/// Returns `None` if the file doesn't have the extension of a supported image
+/// format. The placeholder shows the path of the missing file and is encoded
+/// in the format that the extension implies.
+pub fn placeholder(world: &dyn World, id: FileId) -> Option<Bytes> {
+    let path = id.vpath().as_rootless_path();
+    let svg = svg_placeholder(&path.display().to_string());
+    let format = match path.extension()?.to_str()?.to_lowercase().as_str() {
+        "svg" | "svgz" => return Some(svg),
+        "png" => image::ImageFormat::Png,
+        "jpg" | "jpeg" => image::ImageFormat::Jpeg,
+        "gif" => image::ImageFormat::Gif,
+        _ => return None,
+    };
+
+    // Render the SVG placeholder with Typst's fonts so that the raster
+    // placeholder shows the path, too.
+    let image = Image::with_fonts(
+        svg,
+        ImageFormat::Vector(VectorFormat::Svg),
+        None,
+        world.track(),
+        &["libertinus serif", "new computer modern"],
+    )
+    .ok()?;
+    let size = Size::new(Abs::pt(image.width()), Abs::pt(image.height()));
+    let mut frame = Frame::soft(size);
+    frame.push(Point::zero(), FrameItem::Image(image, size, Span::detached()));
+    let page = Page {
+        frame,
+        fill: Smart::Auto,
+        numbering: None,
+        number: 1,
+        bleed: Abs::zero(),
+        slug: Abs::zero(),
+    };
+
+    let pixmap = typst_render::render(&page, 1.0);
+    let rgba =
+        image::RgbaImage::from_raw(pixmap.width(), pixmap.height(), pixmap.take())?;
+    let mut buf = io::Cursor::new(vec![]);
+    image::DynamicImage::ImageRgba8(rgba)
+        .to_rgb8()
+        .write_to(&mut buf, format)
+        .ok()?;
+    Some(Bytes::from(buf.into_inner()))
+}
+
+/// Creates an SVG placeholder that shows the path of the missing image.
+fn svg_placeholder(path: &str) -> Bytes {
+    let mut escaped = String::new();
+    for c in path.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+
+    let svg = format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="240" height="135">
+  <rect x="0.5" y="0.5" width="239" height="134" fill="#eeeeee" stroke="#aaaaaa" stroke-dasharray="4 2"/>
+  <path d="M0 0L240 135M240 0L0 135" stroke="#cccccc"/>
+  <text x="120" y="72" font-family="sans-serif" font-size="11" text-anchor="middle">{escaped}</text>
+</svg>"##
+    );
+
+    Bytes::from(svg.into_bytes())
+}
+
 /// Decode UTF-8 with an optional BOM.
 fn decode_utf8(buf: &[u8]) -> FileResult<&str> {
     // Remove UTF-8 BOM.
@@ -479,3 +612,15 @@ impl From<WorldCreationError> for EcoString {
         eco_format!("{err}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_svg_placeholder() {
+        let svg = svg_placeholder("images/<a>.svg");
+        let svg = std::str::from_utf8(&svg).unwrap();
+        assert!(svg.contains("images/&lt;a&gt;.svg"));
+    }
+}
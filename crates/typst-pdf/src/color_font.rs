@@ -13,7 +13,7 @@ use pdf_writer::types::UnicodeCmap;
 use pdf_writer::{Filter, Finish, Name, Rect, Ref};
 use ttf_parser::name_id;
 use typst::diag::SourceResult;
-use typst::layout::Em;
+use typst::layout::{Abs, Em};
 use typst::text::color::frame_for_glyph;
 use typst::text::Font;
 
@@ -255,6 +255,7 @@ impl ColorFontMap<()> {
                 &mut self.resources,
                 &frame,
                 None,
+                Abs::zero(),
                 Some(width as f32),
             )?;
             color_font.glyphs.push(ColorGlyph { gid, instructions });
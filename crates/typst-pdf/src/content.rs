@@ -34,6 +34,9 @@ use crate::{deflate_deferred, AbsExt, EmExt, PdfOptions};
 ///
 /// The resources that were used in the stream will be added to `resources`.
 ///
+/// The `fill` is painted behind the frame and extends `bleed` beyond it on
+/// each side.
+///
 /// `color_glyph_width` should be `None` unless the `Frame` represents a [color
 /// glyph].
 ///
@@ -43,6 +46,7 @@ pub fn build(
     resources: &mut Resources<()>,
     frame: &Frame,
     fill: Option<Paint>,
+    bleed: Abs,
     color_glyph_width: Option<f32>,
 ) -> SourceResult<Encoded> {
     let size = frame.size();
@@ -61,8 +65,8 @@ pub fn build(
     );
 
     if let Some(fill) = fill {
-        let shape = Geometry::Rect(frame.size()).filled(fill);
-        write_shape(&mut ctx, Point::zero(), &shape)?;
+        let shape = Geometry::Rect(frame.size() + Size::splat(2.0 * bleed)).filled(fill);
+        write_shape(&mut ctx, Point::splat(-bleed), &shape)?;
     }
 
     // Encode the frame into the content stream.
@@ -71,6 +71,7 @@ fn construct_page(
             out,
             &page.frame,
             page.fill_or_transparent(),
+            page.bleed,
             None,
         )?,
         label: None,
@@ -180,9 +181,18 @@ fn write_page(
     let mut page_writer = chunk.page(page_ref);
     page_writer.parent(page_tree_ref);
 
+    // The content's origin is at the trimmed page's corner. With bleed, the
+    // bleed area and the slug extend beyond it on each side.
     let w = page.content.size.x.to_f32();
     let h = page.content.size.y.to_f32();
-    page_writer.media_box(Rect::new(0.0, 0.0, w, h));
+    let bleed = ctx.document.pages[i].bleed.to_f32();
+    let media = bleed + ctx.document.pages[i].slug.to_f32();
+    page_writer.media_box(Rect::new(-media, -media, w + media, h + media));
+    if bleed > 0.0 {
+        page_writer.bleed_box(Rect::new(-bleed, -bleed, w + bleed, h + bleed));
+        page_writer.trim_box(Rect::new(0.0, 0.0, w, h));
+    }
+
     page_writer.contents(content_id);
     page_writer.pair(Name(b"Resources"), ctx.resources.reference);
 
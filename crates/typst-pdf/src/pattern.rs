@@ -111,6 +111,7 @@ fn register_pattern(
         &mut patterns.resources,
         pattern.frame(),
         None,
+        Abs::zero(),
         None,
     )?;
 
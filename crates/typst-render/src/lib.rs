@@ -10,6 +10,7 @@ use typst::layout::{
     Abs, Axes, Frame, FrameItem, FrameKind, GroupItem, Page, Point, Size, Transform,
 };
 use typst::model::Document;
+use typst::utils::Numeric;
 use typst::visualize::{Color, Geometry, Paint};
 
 /// Export a page into a raster image.
@@ -18,21 +19,34 @@ use typst::visualize::{Color, Geometry, Paint};
 /// the resulting `tiny-skia` pixel buffer.
 #[typst_macros::time(name = "render")]
 pub fn render(page: &Page, pixel_per_pt: f32) -> sk::Pixmap {
-    let size = page.frame.size();
+    let size = page.media_size();
     let pxw = (pixel_per_pt * size.x.to_f32()).round().max(1.0) as u32;
     let pxh = (pixel_per_pt * size.y.to_f32()).round().max(1.0) as u32;
 
-    let ts = sk::Transform::from_scale(pixel_per_pt, pixel_per_pt);
+    // The trimmed page sits inside of the bleed area and the slug.
+    let offset = (page.bleed + page.slug).to_f32();
+    let ts = sk::Transform::from_scale(pixel_per_pt, pixel_per_pt)
+        .pre_translate(offset, offset);
     let state = State::new(size, ts, pixel_per_pt);
 
     let mut canvas = sk::Pixmap::new(pxw, pxh).unwrap();
 
+    // The slug is left blank like the paper it is printed on, while the fill
+    // covers the bleed area.
+    if !page.slug.is_zero() {
+        canvas.fill(sk::Color::WHITE);
+    }
+
     if let Some(fill) = page.fill_or_white() {
-        if let Paint::Solid(color) = fill {
-            canvas.fill(paint::to_sk_color(color));
-        } else {
-            let rect = Geometry::Rect(page.frame.size()).filled(fill);
-            shape::render_shape(&mut canvas, state, &rect);
+        match fill {
+            Paint::Solid(color) if page.slug.is_zero() => {
+                canvas.fill(paint::to_sk_color(color));
+            }
+            fill => {
+                let rect = Geometry::Rect(page.bleed_size()).filled(fill);
+                let state = state.pre_translate(Point::splat(-page.bleed));
+                shape::render_shape(&mut canvas, state, &rect);
+            }
         }
     }
 
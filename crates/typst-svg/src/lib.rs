@@ -14,8 +14,8 @@ use typst::layout::{
     Abs, Frame, FrameItem, FrameKind, GroupItem, Page, Point, Ratio, Size, Transform,
 };
 use typst::model::Document;
-use typst::utils::hash128;
-use typst::visualize::{Geometry, Gradient, Pattern};
+use typst::utils::{hash128, Numeric};
+use typst::visualize::{Color, Geometry, Gradient, Pattern, Shape};
 use xmlwriter::XmlWriter;
 
 use crate::paint::{GradientRef, PatternRef, SVGSubGradient};
@@ -25,9 +25,9 @@ use crate::text::RenderedGlyph;
 #[typst_macros::time(name = "svg")]
 pub fn svg(page: &Page) -> String {
     let mut renderer = SVGRenderer::new();
-    renderer.write_header(page.frame.size());
+    renderer.write_header(page.media_size());
 
-    let state = State::new(page.frame.size(), Transform::identity());
+    let state = State::new(page.media_size(), Transform::identity());
     renderer.render_page(state, Transform::identity(), page);
     renderer.finalize()
 }
@@ -40,14 +40,14 @@ pub fn svg_merged(document: &Document, padding: Abs) -> String {
         + document
             .pages
             .iter()
-            .map(|page| page.frame.width())
+            .map(|page| page.media_size().x)
             .max()
             .unwrap_or_default();
     let height = padding
         + document
             .pages
             .iter()
-            .map(|page| page.frame.height() + padding)
+            .map(|page| page.media_size().y + padding)
             .sum::<Abs>();
 
     let mut renderer = SVGRenderer::new();
@@ -56,9 +56,9 @@ pub fn svg_merged(document: &Document, padding: Abs) -> String {
     let [x, mut y] = [padding; 2];
     for page in &document.pages {
         let ts = Transform::translate(x, y);
-        let state = State::new(page.frame.size(), Transform::identity());
+        let state = State::new(page.media_size(), Transform::identity());
         renderer.render_page(state, ts, page);
-        y += page.frame.height() + padding;
+        y += page.media_size().y + padding;
     }
 
     renderer.finalize()
@@ -178,14 +178,36 @@ impl SVGRenderer {
 
     /// Render a page with the given transform.
     fn render_page(&mut self, state: State, ts: Transform, page: &Page) {
+        // The slug is left blank like the paper it is printed on, while the
+        // fill covers the bleed area.
+        if !page.slug.is_zero() {
+            let shape = Geometry::Rect(page.media_size()).filled(Color::WHITE.into());
+            self.render_page_shape(state, ts, &shape);
+        }
+
+        // The trimmed page sits inside of the bleed area and the slug.
+        let offset = page.bleed + page.slug;
+        let ts = ts.pre_concat(Transform::translate(offset, offset));
+
         if let Some(fill) = page.fill_or_white() {
-            let shape = Geometry::Rect(page.frame.size()).filled(fill);
-            self.render_shape(state, &shape);
+            let shape = Geometry::Rect(page.bleed_size()).filled(fill);
+            let bleed_ts = ts.pre_concat(Transform::translate(-page.bleed, -page.bleed));
+            self.render_page_shape(state, bleed_ts, &shape);
         }
 
         self.render_frame(state, ts, &page.frame);
     }
 
+    /// Render a shape that covers (part of) a page with the given transform.
+    fn render_page_shape(&mut self, state: State, ts: Transform, shape: &Shape) {
+        self.xml.start_element("g");
+        if !ts.is_identity() {
+            self.xml.write_attribute("transform", &SvgMatrix(ts));
+        }
+        self.render_shape(state, shape);
+        self.xml.end_element();
+    }
+
     /// Render a frame with the given transform.
     fn render_frame(&mut self, state: State, ts: Transform, frame: &Frame) {
         self.xml.start_element("g");
@@ -5,6 +5,8 @@ use std::ops::{Add, AddAssign};
 
 use comemo::Tracked;
 use ecow::{eco_format, EcoString, EcoVec};
+use icu_collator::{Collator, CollatorOptions};
+use icu_locid::Locale;
 use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 
@@ -815,6 +817,13 @@ impl Array {
     ///
     /// Returns an error if two values could not be compared or if the key
     /// function (if given) yields an error.
+    ///
+    /// ```example
+    /// #let words = ("Zebra", "Äpfel", "Apfel")
+    /// #words.sorted() \
+    /// #words.sorted(locale: "de") \
+    /// #words.sorted(locale: "sv")
+    /// ```
     #[func]
     pub fn sorted(
         self,
@@ -828,7 +837,17 @@ impl Array {
         /// determine the keys to sort by.
         #[named]
         key: Option<Func>,
+        /// If given, strings are sorted according to the alphabetical order
+        /// of this locale, like `{"de"}` or `{"sv-SE"}`, instead of by their
+        /// Unicode codepoints.
+        #[named]
+        locale: Option<Spanned<Str>>,
     ) -> SourceResult<Array> {
+        let collator = match &locale {
+            Some(Spanned { v, span }) => Some(collator(v).at(*span)?),
+            None => None,
+        };
+
         let mut result = Ok(());
         let mut vec = self.0;
         let mut key_of = |x: Value| match &key {
@@ -840,7 +859,13 @@ impl Array {
         vec.make_mut().sort_by(|a, b| {
             // Until we get `try` blocks :)
             match (key_of(a.clone()), key_of(b.clone())) {
-                (Ok(a), Ok(b)) => ops::compare(&a, &b).unwrap_or_else(|err| {
+                (Ok(a), Ok(b)) => match (&collator, &a, &b) {
+                    (Some(collator), Value::Str(a), Value::Str(b)) => {
+                        Ok(collator.compare(a, b))
+                    }
+                    _ => ops::compare(&a, &b),
+                }
+                .unwrap_or_else(|err| {
                     if result.is_ok() {
                         result = Err(err).at(span);
                     }
@@ -1106,6 +1131,14 @@ impl<T: FromValue, const N: usize> FromValue for SmallVec<[T; N]> {
     }
 }
 
+/// Create a collator that orders strings like the given locale does.
+fn collator(locale: &str) -> StrResult<Collator> {
+    let parsed: Locale =
+        locale.parse().map_err(|_| eco_format!("invalid locale `{locale}`"))?;
+    Collator::try_new(&(&parsed).into(), CollatorOptions::new())
+        .map_err(|_| eco_format!("sorting is not supported for locale `{locale}`"))
+}
+
 /// The error message when the array is empty.
 #[cold]
 fn array_is_empty() -> EcoString {
@@ -5,9 +5,10 @@ use std::sync::Arc;
 
 use ecow::{eco_format, EcoString};
 use serde::{Serialize, Serializer};
+use sha2::Digest;
 
 use crate::diag::{bail, StrResult};
-use crate::foundations::{cast, func, scope, ty, Array, Reflect, Repr, Str, Value};
+use crate::foundations::{cast, func, scope, ty, Array, Cast, Reflect, Repr, Str, Value};
 use crate::utils::LazyHash;
 
 /// A sequence of bytes.
@@ -152,6 +153,52 @@ impl Bytes {
         let end = self.locate(end.unwrap_or(self.len() as i64))?.max(start);
         Ok(self.0[start..end].into())
     }
+
+    /// Calculates a cryptographic hash of the bytes.
+    ///
+    /// The hash is returned as a string of lowercase hexadecimal digits. To
+    /// hash a string, convert it to bytes first.
+    ///
+    /// ```example
+    /// #bytes("typst").hash() \
+    /// #bytes("typst").hash(algorithm: "md5")
+    /// ```
+    #[func(name = "hash")]
+    pub fn digest(
+        &self,
+        /// The hash function to use.
+        #[named]
+        #[default(HashAlgorithm::Sha256)]
+        algorithm: HashAlgorithm,
+    ) -> Str {
+        match algorithm {
+            HashAlgorithm::Md5 => hex(&md5::Md5::digest(self.as_slice())),
+            HashAlgorithm::Sha1 => hex(&sha1::Sha1::digest(self.as_slice())),
+            HashAlgorithm::Sha256 => hex(&sha2::Sha256::digest(self.as_slice())),
+        }
+        .into()
+    }
+}
+
+/// A hash function supported by [`Bytes::digest`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum HashAlgorithm {
+    /// MD5, which is fast but broken for security purposes.
+    Md5,
+    /// SHA-1, which is broken for security purposes.
+    Sha1,
+    /// SHA-256 from the SHA-2 family.
+    Sha256,
+}
+
+/// Formats bytes as lowercase hexadecimal digits.
+pub(crate) fn hex(bytes: &[u8]) -> EcoString {
+    use std::fmt::Write;
+    let mut buf = EcoString::with_capacity(2 * bytes.len());
+    for byte in bytes {
+        write!(buf, "{byte:02x}").unwrap();
+    }
+    buf
 }
 
 impl Debug for Bytes {
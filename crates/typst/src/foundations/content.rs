@@ -420,6 +420,7 @@ impl Content {
             if selector.matches(&element, None) {
                 results.push(element);
             }
+            true
         });
         results
     }
@@ -434,6 +435,7 @@ impl Content {
             if result.is_none() && selector.matches(&element, None) {
                 result = Some(element);
             }
+            true
         });
         result
     }
@@ -444,17 +446,23 @@ impl Content {
         self.traverse(&mut |element| {
             if let Some(textable) = element.with::<dyn PlainText>() {
                 textable.plain_text(&mut text);
+                return false;
             }
+            true
         });
         text
     }
 
     /// Traverse this content.
+    ///
+    /// If `f` returns `false` for an element, its fields are skipped.
     fn traverse<F>(&self, f: &mut F)
     where
-        F: FnMut(Content),
+        F: FnMut(Content) -> bool,
     {
-        f(self.clone());
+        if !f(self.clone()) {
+            return;
+        }
 
         self.inner
             .elem
@@ -465,7 +473,7 @@ impl Content {
         /// Walks a given value to find any content that matches the selector.
         fn walk_value<F>(value: Value, f: &mut F)
         where
-            F: FnMut(Content),
+            F: FnMut(Content) -> bool,
         {
             match value {
                 Value::Content(content) => content.traverse(f),
@@ -586,6 +594,20 @@ impl Content {
         dict
     }
 
+    /// Extracts the plain text of this content, discarding all formatting.
+    ///
+    /// Content without a textual representation, like images and shapes, is
+    /// skipped. Math is written in a linear form. This is useful to produce
+    /// strings for metadata or to compare content in assertions.
+    ///
+    /// ```example
+    /// #[*Hello* _world!_].plain-text()
+    /// ```
+    #[func(name = "plain-text")]
+    pub fn to_plain_text(&self) -> Str {
+        self.plain_text().into()
+    }
+
     /// The location of the content. This is only available on content returned
     /// by [query] or provided by a [show rule]($reference/styling/#show-rules),
     /// for other content it will be `{none}`. The resulting location can be
@@ -962,6 +984,9 @@ impl Repr for StyledElem {
 }
 
 /// Tries to extract the plain-text representation of the element.
+///
+/// Implementors are responsible for the plain text of the element's children
+/// as well.
 pub trait PlainText {
     /// Write this element's plain text into the given buffer.
     fn plain_text(&self, text: &mut EcoString);
@@ -340,6 +340,9 @@ impl Datetime {
     /// `[[hour]:[minute]:[second]]`. In the case of a datetime, it will be
     /// `[[year]-[month]-[day] [hour]:[minute]:[second]]`.
     ///
+    /// Names of months and weekdays are always in English, independently of
+    /// the [text language]($text.lang).
+    ///
     /// See the [format syntax]($datetime/#format) for more information.
     #[func]
     pub fn display(
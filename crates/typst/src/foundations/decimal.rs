@@ -134,6 +134,15 @@ impl Decimal {
         ))
     }
 
+    /// Formats this decimal with a fixed number of decimal places, rounding it
+    /// if necessary.
+    pub fn display_with_digits(self, digits: usize) -> EcoString {
+        let rounded = self.round(digits.try_into().unwrap_or(u32::MAX));
+        // Avoid displaying a negative zero.
+        let rounded = if rounded.is_zero() { rounded.abs() } else { rounded };
+        eco_format!("{rounded:.digits$}")
+    }
+
     /// Attempts to add two decimals.
     ///
     /// Returns `None` on overflow or underflow.
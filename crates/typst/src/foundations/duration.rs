@@ -4,7 +4,8 @@ use std::ops::{Add, Div, Mul, Neg, Sub};
 use ecow::{eco_format, EcoString};
 use time::ext::NumericalDuration;
 
-use crate::foundations::{func, repr, scope, ty, Repr};
+use crate::diag::{bail, StrResult};
+use crate::foundations::{func, repr, scope, ty, Repr, Smart, Str};
 
 /// Represents a positive or negative span of time.
 #[ty(scope, cast)]
@@ -108,6 +109,138 @@ impl Duration {
     pub fn weeks(&self) -> f64 {
         self.seconds() / 604_800.0
     }
+
+    /// Displays the duration in a specified format.
+    ///
+    /// The pattern may contain the placeholders `[[weeks]]`, `[[days]]`,
+    /// `[[hours]]`, `[[minutes]]`, and `[[seconds]]`. The largest unit in the
+    /// pattern receives everything that does not fit into the smaller ones,
+    /// which are in turn filled with what remains. Hours, minutes, and seconds
+    /// are padded to two digits unless they are the largest unit. Fractions of
+    /// a second are truncated. The default pattern is
+    /// `[[hours]:[minutes]:[seconds]]`.
+    ///
+    /// ```example
+    /// #let d = duration(days: 1, hours: 2, seconds: 5)
+    /// #d.display() \
+    /// #d.display("[days]d [hours]:[minutes]:[seconds]") \
+    /// #d.display("[minutes]m [seconds]s")
+    /// ```
+    #[func]
+    pub fn display(
+        &self,
+        /// The format used to display the duration.
+        #[default]
+        pattern: Smart<Str>,
+    ) -> StrResult<Str> {
+        let pattern = match &pattern {
+            Smart::Auto => "[hours]:[minutes]:[seconds]",
+            Smart::Custom(pattern) => pattern.as_str(),
+        };
+
+        // Split the pattern into literal text and placeholders.
+        let mut pieces = vec![];
+        let mut rest = pattern;
+        while let Some(start) = rest.find('[') {
+            pieces.push(DurationPiece::Literal(&rest[..start]));
+            let Some(end) = rest[start..].find(']') else {
+                bail!("unclosed placeholder in duration pattern");
+            };
+            let name = &rest[start + 1..start + end];
+            let Some(unit) = DurationUnit::from_name(name) else {
+                bail!("unknown duration placeholder `[{name}]`");
+            };
+            pieces.push(DurationPiece::Unit(unit));
+            rest = &rest[start + end + 1..];
+        }
+        pieces.push(DurationPiece::Literal(rest));
+
+        // Distribute the duration onto the units, starting with the largest.
+        let largest = pieces
+            .iter()
+            .filter_map(|piece| match *piece {
+                DurationPiece::Unit(unit) => Some(unit),
+                DurationPiece::Literal(_) => None,
+            })
+            .max();
+        let mut remaining = self.0.whole_seconds().unsigned_abs();
+        let mut values = [0; DurationUnit::ALL.len()];
+        for (i, unit) in DurationUnit::ALL.into_iter().enumerate().rev() {
+            if pieces.contains(&DurationPiece::Unit(unit)) {
+                values[i] = remaining / unit.seconds();
+                remaining %= unit.seconds();
+            }
+        }
+
+        let mut buf = EcoString::new();
+        if self.0.is_negative() {
+            buf.push_str(repr::MINUS_SIGN);
+        }
+
+        for piece in pieces {
+            match piece {
+                DurationPiece::Literal(text) => buf.push_str(text),
+                DurationPiece::Unit(unit) => {
+                    let value = values[unit as usize];
+                    if Some(unit) != largest && unit <= DurationUnit::Hours {
+                        buf.push_str(&eco_format!("{value:02}"));
+                    } else {
+                        buf.push_str(&eco_format!("{value}"));
+                    }
+                }
+            }
+        }
+
+        Ok(buf.into())
+    }
+}
+
+/// A piece of a duration display pattern.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum DurationPiece<'a> {
+    /// Text that is displayed as is.
+    Literal(&'a str),
+    /// A placeholder for the value of a unit.
+    Unit(DurationUnit),
+}
+
+/// A unit that a duration can be displayed in.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+enum DurationUnit {
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+}
+
+impl DurationUnit {
+    /// All units, from smallest to largest.
+    const ALL: [Self; 5] =
+        [Self::Seconds, Self::Minutes, Self::Hours, Self::Days, Self::Weeks];
+
+    /// The unit for a placeholder name.
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "seconds" => Self::Seconds,
+            "minutes" => Self::Minutes,
+            "hours" => Self::Hours,
+            "days" => Self::Days,
+            "weeks" => Self::Weeks,
+            _ => return None,
+        })
+    }
+
+    /// How many seconds the unit spans.
+    fn seconds(self) -> u64 {
+        match self {
+            Self::Seconds => 1,
+            Self::Minutes => 60,
+            Self::Hours => 3_600,
+            Self::Days => 86_400,
+            Self::Weeks => 604_800,
+        }
+    }
 }
 
 impl Debug for Duration {
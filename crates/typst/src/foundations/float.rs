@@ -7,6 +7,7 @@ use crate::foundations::{
     bail, cast, func, repr, scope, ty, Bytes, Decimal, Endianness, Repr, Str,
 };
 use crate::layout::Ratio;
+use crate::utils::Scalar;
 
 /// A floating-point number.
 ///
@@ -169,6 +170,12 @@ impl Repr for f64 {
     }
 }
 
+cast! {
+    Scalar,
+    self => self.get().into_value(),
+    v: f64 => Self::new(v),
+}
+
 /// A value that can be cast to a float.
 pub struct ToFloat(f64);
 
@@ -76,10 +76,10 @@ pub use {
 
 use ecow::EcoString;
 
-use crate::diag::{bail, SourceResult, StrResult};
+use crate::diag::{bail, warning, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::eval::EvalMode;
-use crate::syntax::Spanned;
+use crate::syntax::{Span, Spanned};
 
 /// Foundational types and functions.
 ///
@@ -114,6 +114,7 @@ pub(super) fn define(global: &mut Scope, inputs: Dict) {
     global.define_func::<repr::repr>();
     global.define_func::<panic>();
     global.define_func::<assert>();
+    global.define_func::<debug>();
     global.define_func::<eval>();
     global.define_func::<style>();
     global.define_module(calc::module());
@@ -149,6 +150,45 @@ pub fn panic(
     Err(msg)
 }
 
+/// Prints values to the compiler output for debugging.
+///
+/// The values are reported as a warning at the location of the call, so that
+/// you can trace what your code computes without changing the document.
+/// Strings are printed as they are, all other values are converted with
+/// [`repr`].
+///
+/// # Example
+/// The code below produces the warning `debug: heading level is 2`.
+/// ```typ
+/// #show heading: it => {
+///   debug("heading level is", it.level)
+///   it
+/// }
+///
+/// == Introduction
+/// ```
+#[func(keywords = ["print", "log"])]
+pub fn debug(
+    /// The engine.
+    engine: &mut Engine,
+    /// The call span of this function.
+    span: Span,
+    /// The values to print.
+    #[variadic]
+    values: Vec<Value>,
+) -> NoneValue {
+    let mut msg = EcoString::from("debug:");
+    for value in values {
+        msg.push(' ');
+        match value {
+            Value::Str(string) => msg.push_str(&string),
+            value => msg.push_str(&value.repr()),
+        }
+    }
+    engine.sink.warn(warning!(span, "{msg}"));
+    NoneValue
+}
+
 /// Ensures that a condition is fulfilled.
 ///
 /// Fails with an error if the condition is not fulfilled. Does not
@@ -258,6 +298,23 @@ impl assert {
 /// #eval("(1, 2, 3, 4)").len() \
 /// #eval("*Markup!*", mode: "markup") \
 /// ```
+///
+/// # Sandboxing
+/// The evaluated code runs in a fresh scope: It can use the standard library
+/// and the definitions passed via `scope`, but not the variables around the
+/// call. This makes it possible to evaluate snippets that come from data
+/// files, for instance formulas stored in a CSV file:
+///
+/// ```example
+/// #let rows = (
+///   ("Area", "pi r^2"),
+///   ("Circumference", "2 pi r"),
+/// )
+///
+/// #for (name, formula) in rows [
+///   #name: #eval(formula, mode: "math") \
+/// ]
+/// ```
 #[func(title = "Evaluate")]
 pub fn eval(
     /// The engine.
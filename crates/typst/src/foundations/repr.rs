@@ -129,6 +129,21 @@ pub fn display_float(value: f64) -> EcoString {
     }
 }
 
+/// Converts a float to a string that can be used to display the float as text,
+/// with a fixed number of decimal places.
+pub fn display_float_with_digits(value: f64, digits: usize) -> EcoString {
+    if !value.is_finite() {
+        return display_float(value);
+    }
+
+    let formatted = eco_format!("{:.*}", digits, value.abs());
+    if value < 0.0 && formatted.bytes().any(|b| matches!(b, b'1'..=b'9')) {
+        eco_format!("{MINUS_SIGN}{formatted}")
+    } else {
+        formatted
+    }
+}
+
 /// Formats pieces separated with commas and a final "and" or "or".
 pub fn separated_list(pieces: &[impl AsRef<str>], last: &str) -> String {
     let mut buf = String::new();
@@ -6,15 +6,18 @@ use std::ops::{Add, AddAssign, Deref, Range};
 use comemo::Tracked;
 use ecow::EcoString;
 use serde::{Deserialize, Serialize};
+use sha1::Digest;
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::diag::{bail, At, SourceResult, StrResult};
 use crate::engine::Engine;
+use crate::foundations::bytes::hex;
 use crate::foundations::{
     cast, dict, func, repr, scope, ty, Array, Bytes, Context, Decimal, Dict, Func,
-    IntoValue, Label, Repr, Type, Value, Version,
+    IntoValue, Label, Repr, Smart, Type, Value, Version,
 };
 use crate::layout::Alignment;
+use crate::loading::Readable;
 use crate::syntax::{Span, Spanned};
 use crate::utils::PicoStr;
 
@@ -131,18 +134,25 @@ impl Str {
     /// - Integers are formatted in base 10. This can be overridden with the
     ///   optional `base` parameter.
     /// - Floats are formatted in base 10 and never in exponential notation.
+    /// - Decimals are formatted in base 10.
+    /// - With the `digits` parameter, integers, floats, and decimals are
+    ///   formatted with a fixed number of decimal places.
     /// - From labels the name is extracted.
     /// - Bytes are decoded as UTF-8.
     ///
     /// If you wish to convert from and to Unicode code points, see the
     /// [`to-unicode`]($str.to-unicode) and [`from-unicode`]($str.from-unicode)
-    /// functions.
+    /// functions. To separate the thousands of a number, use
+    /// [`text.num`]($text.num), which follows the conventions of the current
+    /// language.
     ///
     /// ```example
     /// #str(10) \
     /// #str(4000, base: 16) \
     /// #str(2.7) \
     /// #str(1e8) \
+    /// #str(3.14159, digits: 2) \
+    /// #str(decimal("1.005"), digits: 2) \
     /// #str(<intro>)
     /// ```
     #[func(constructor)]
@@ -153,7 +163,18 @@ impl Str {
         #[named]
         #[default(Spanned::new(10, Span::detached()))]
         base: Spanned<i64>,
+        /// The number of decimal places to display numbers with.
+        ///
+        /// Floats and decimals are rounded if necessary. If `{auto}`, as many digits as
+        /// needed to represent the number are shown.
+        #[named]
+        #[default(Spanned::new(Smart::Auto, Span::detached()))]
+        digits: Spanned<Smart<usize>>,
     ) -> SourceResult<Str> {
+        if digits.v.is_custom() && matches!(value, ToStr::Str(_)) {
+            bail!(digits.span, "digits are only supported for numbers");
+        }
+
         Ok(match value {
             ToStr::Str(s) => {
                 if base.v != 10 {
@@ -165,7 +186,35 @@ impl Str {
                 if base.v < 2 || base.v > 36 {
                     bail!(base.span, "base must be between 2 and 36");
                 }
-                repr::format_int_with_base(n, base.v).into()
+                let mut s = repr::format_int_with_base(n, base.v);
+                if let Smart::Custom(count @ 1..) = digits.v {
+                    if base.v != 10 {
+                        bail!(digits.span, "digits are only supported in base 10");
+                    }
+                    s.push('.');
+                    s.push_str(&"0".repeat(count));
+                }
+                s.into()
+            }
+            ToStr::Float(v) => {
+                if base.v != 10 {
+                    bail!(base.span, "base is only supported for integers");
+                }
+                match digits.v {
+                    Smart::Custom(count) => {
+                        repr::display_float_with_digits(v, count).into()
+                    }
+                    Smart::Auto => repr::display_float(v).into(),
+                }
+            }
+            ToStr::Decimal(v) => {
+                if base.v != 10 {
+                    bail!(base.span, "base is only supported for integers");
+                }
+                match digits.v {
+                    Smart::Custom(count) => v.display_with_digits(count).into(),
+                    Smart::Auto => format_str!("{}", v),
+                }
             }
         })
     }
@@ -285,6 +334,65 @@ impl Str {
         Ok(c.into())
     }
 
+    /// Derives a name-based UUID (version 5).
+    ///
+    /// The same name in the same namespace always results in the same UUID,
+    /// which makes it possible to generate stable identifiers for the things
+    /// described in a document.
+    ///
+    /// ```example
+    /// #str.uuid("https://typst.app") \
+    /// #str.uuid("typst.app", namespace: "dns")
+    /// ```
+    #[func(title = "UUID")]
+    pub fn uuid(
+        /// The name to derive the UUID from. Strings are hashed in their UTF-8
+        /// encoding.
+        name: Readable,
+        /// The namespace the name lives in.
+        ///
+        /// This is either one of the predefined namespaces `{"dns"}`, `{"url"}`,
+        /// `{"oid"}`, and `{"x500"}` or another UUID in its hyphenated form.
+        #[named]
+        #[default(Spanned::new("url".into(), Span::detached()))]
+        namespace: Spanned<Str>,
+    ) -> SourceResult<Str> {
+        let uuid = match namespace.v.as_str() {
+            "dns" => "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+            "url" => "6ba7b811-9dad-11d1-80b4-00c04fd430c8",
+            "oid" => "6ba7b812-9dad-11d1-80b4-00c04fd430c8",
+            "x500" => "6ba7b814-9dad-11d1-80b4-00c04fd430c8",
+            other => other,
+        };
+
+        let digits: String = uuid.chars().filter(|&c| c != '-').collect();
+        if digits.len() != 32 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            bail!(namespace.span, "expected predefined namespace or UUID");
+        }
+
+        let mut hasher = sha1::Sha1::new();
+        for i in (0..32).step_by(2) {
+            hasher.update([u8::from_str_radix(&digits[i..i + 2], 16).unwrap()]);
+        }
+        hasher.update(name.as_slice());
+
+        let mut bytes = [0; 16];
+        bytes.copy_from_slice(&hasher.finalize()[..16]);
+        bytes[6] = (bytes[6] & 0x0f) | 0x50;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+        let hex = hex(&bytes);
+        Ok(eco_format!(
+            "{}-{}-{}-{}-{}",
+            &hex[..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..],
+        )
+        .into())
+    }
+
     /// Whether the string contains the specified pattern.
     ///
     /// This method also has dedicated syntax: You can write `{"bc" in "abcd"}`
@@ -593,6 +701,41 @@ impl Str {
         }
     }
 
+    /// Pads the string with a fill string until it has at least the given
+    /// number of grapheme clusters.
+    ///
+    /// ```example
+    /// #"7".pad(3, fill: "0") \
+    /// #"Total".pad(8, fill: ".", at: end)
+    /// ```
+    #[func]
+    pub fn pad(
+        &self,
+        /// The minimum number of grapheme clusters of the resulting string.
+        width: usize,
+        /// The string to pad with. Must consist of a single grapheme cluster.
+        #[named]
+        #[default(Str::from(" "))]
+        fill: Str,
+        /// Can be `{start}` or `{end}` to pad at the start or end of the
+        /// string.
+        #[named]
+        #[default(StrSide::Start)]
+        at: StrSide,
+    ) -> StrResult<Str> {
+        if fill.as_str().graphemes(true).count() != 1 {
+            bail!("fill must be exactly one grapheme cluster");
+        }
+
+        let count = self.as_str().graphemes(true).count();
+        let padding = fill.as_str().repeat(width.saturating_sub(count));
+        Ok(match at {
+            StrSide::Start => padding + self.as_str(),
+            StrSide::End => self.as_str().to_string() + &padding,
+        }
+        .into())
+    }
+
     /// Reverse the string.
     #[func(title = "Reverse")]
     pub fn rev(&self) -> Str {
@@ -769,13 +912,17 @@ pub enum ToStr {
     Str(Str),
     /// An integer about to be formatted in a given base.
     Int(i64),
+    /// A float about to be formatted with a given number of decimal places.
+    Float(f64),
+    /// A decimal about to be formatted with a given number of decimal places.
+    Decimal(Decimal),
 }
 
 cast! {
     ToStr,
     v: i64 => Self::Int(v),
-    v: f64 => Self::Str(repr::display_float(v).into()),
-    v: Decimal => Self::Str(format_str!("{}", v)),
+    v: f64 => Self::Float(v),
+    v: Decimal => Self::Decimal(v),
     v: Version => Self::Str(format_str!("{}", v)),
     v: Bytes => Self::Str(
         std::str::from_utf8(&v)
@@ -942,6 +1089,10 @@ pub enum StrSide {
 
 cast! {
     StrSide,
+    self => match self {
+        Self::Start => Alignment::START.into_value(),
+        Self::End => Alignment::END.into_value(),
+    },
     v: Alignment => match v {
         Alignment::START => Self::Start,
         Alignment::END => Self::End,
@@ -19,6 +19,16 @@ use crate::foundations::{cast, func, repr, scope, ty, Repr};
 /// as `0`, `0.0`, `0.0.0`, and so on.
 ///
 /// The current version of the Typst compiler is available as `sys.version`.
+/// Since versions can be compared, templates and packages can use it to check
+/// whether the compiler is recent enough for them:
+///
+/// ```example
+/// #if sys.version < version(0, 11) {
+///   panic("this template requires Typst 0.11 or newer")
+/// }
+///
+/// Running on Typst #sys.version.
+/// ```
 ///
 /// You can convert a version to an array of explicitly given components using
 /// the [`array`] constructor.
@@ -94,6 +94,17 @@ impl Introspector {
         self.elems.values().map(|(c, _)| c)
     }
 
+    /// Finds the first element that did not exist in `other` or that was
+    /// located at a different position there.
+    ///
+    /// This is used to report which element prevented layout from converging.
+    pub fn first_difference(&self, other: &Self) -> Option<&Content> {
+        self.elems
+            .iter()
+            .find(|(loc, (_, pos))| other.elems.get(*loc).map(|(_, p)| p) != Some(pos))
+            .map(|(_, (elem, _))| elem)
+    }
+
     /// Perform a binary search for `elem` among the `list`.
     fn binary_search(&self, list: &[Content], elem: &Content) -> Result<usize, usize> {
         list.binary_search_by_key(&self.elem_index(elem), |elem| self.elem_index(elem))
@@ -24,6 +24,26 @@ use crate::introspection::Locatable;
 ///   query(<note>).first().value
 /// }
 /// ```
+///
+/// # Speaker notes
+/// Since metadata is invisible, it can also carry content that should not be
+/// part of the output at all, like the speaker notes of a presentation. A
+/// slide template can attach the notes to each page and a presenter tool can
+/// then extract them together with their page numbers:
+///
+/// ```example
+/// #let notes(body) = [#metadata(body) <notes>]
+///
+/// = Introduction
+/// #notes[Greet the audience first.]
+///
+/// #context for note in query(<notes>) [
+///   Page #note.location().page(): #note.value
+/// ]
+/// ```
+///
+/// From the command line, `typst query slides.typ "<notes>" --field value`
+/// prints all notes in document order.
 #[elem(Show, Locatable)]
 pub struct MetadataElem {
     /// The value to embed into the document.
@@ -1,12 +1,13 @@
 use std::ops::Add;
 
+use comemo::Tracked;
 use ecow::{eco_format, EcoString};
 
 use crate::diag::{bail, HintedStrResult, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, func, scope, ty, CastInfo, Content, Fold, FromValue, IntoValue, Packed,
-    Reflect, Repr, Resolve, Show, StyleChain, Value,
+    cast, elem, func, scope, ty, CastInfo, Content, Context, Fold, FromValue, IntoValue,
+    Packed, Reflect, Repr, Resolve, Show, StyleChain, Value,
 };
 use crate::layout::{Abs, Axes, Axis, Dir, Side};
 use crate::text::TextElem;
@@ -173,6 +174,33 @@ impl Alignment {
             Self::Both(h, v) => Self::Both(h.inv(), v.inv()),
         }
     }
+
+    /// Resolves `start` and `end` to `left` and `right` according to the
+    /// current [text direction]($text.dir). Other alignments stay unchanged.
+    ///
+    /// This is useful for templates that need to make decisions based on the
+    /// physical side something is aligned to, but should work for both
+    /// left-to-right and right-to-left documents.
+    ///
+    /// ```example
+    /// #context (start + top).to-absolute() \
+    /// #set text(dir: rtl)
+    /// #context (start + top).to-absolute()
+    /// ```
+    #[func(contextual)]
+    pub fn to_absolute(self, context: Tracked<Context>) -> HintedStrResult<Alignment> {
+        let dir = TextElem::dir_in(context.styles()?);
+        let fix = |h: HAlignment| match h.fix(dir) {
+            FixedAlignment::Start => HAlignment::Left,
+            FixedAlignment::Center => HAlignment::Center,
+            FixedAlignment::End => HAlignment::Right,
+        };
+        Ok(match self {
+            Self::H(h) => Self::H(fix(h)),
+            Self::V(v) => Self::V(v),
+            Self::Both(h, v) => Self::Both(fix(h), v),
+        })
+    }
 }
 
 impl Default for Alignment {
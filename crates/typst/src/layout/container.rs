@@ -13,7 +13,7 @@ use crate::layout::{
     FrameKind, Length, Region, Regions, Rel, Sides, Size, Spacing,
 };
 use crate::utils::Numeric;
-use crate::visualize::{clip_rect, Paint, Stroke};
+use crate::visualize::{clip_rect, FixedStroke, Paint, Stroke};
 
 /// An inline-level container that sizes content.
 ///
@@ -31,6 +31,25 @@ use crate::visualize::{clip_rect, Paint, Stroke};
 /// )
 /// for more information.
 /// ```
+///
+/// # Form fields
+/// An empty box with a bottom stroke sits exactly on the baseline, which makes
+/// it a good building block for fill-in lines in contracts and paper forms.
+/// Combined with the [ballot symbols]($category/symbols/sym), you can build
+/// simple form helpers:
+///
+/// ```example
+/// #let field(label, width: 1fr) = {
+///   label
+///   h(0.5em)
+///   box(width: width, stroke: (bottom: 0.5pt))
+/// }
+///
+/// #field[Name] \
+/// #field(width: 3cm)[Date] \
+/// #sym.ballot Accepted #h(1em)
+/// #sym.ballot.cross Declined
+/// ```
 #[elem]
 pub struct BoxElem {
     /// The width of the box.
@@ -334,6 +353,27 @@ pub struct BlockElem {
     #[default(true)]
     pub breakable: bool,
 
+    /// Whether the block's stroke and corner radius are left open where it is
+    /// broken across pages or columns.
+    ///
+    /// By default, each part of a broken block is decorated like a full block.
+    /// If this is `{true}`, only the first part gets the top stroke and
+    /// rounded top corners and only the last part gets the bottom ones. This
+    /// way, the parts look like one continuous block.
+    ///
+    /// ```example
+    /// #set page(height: 80pt)
+    /// #block(
+    ///   stroke: blue,
+    ///   radius: 4pt,
+    ///   inset: 6pt,
+    ///   open-edges: true,
+    ///   lorem(20),
+    /// )
+    /// ```
+    #[default(false)]
+    pub open_edges: bool,
+
     /// The block's background color. See the
     /// [rectangle's documentation]($rect.fill) for more details.
     pub fill: Option<Paint>,
@@ -390,6 +430,21 @@ pub struct BlockElem {
     pub spacing: Spacing,
 
     /// The spacing between this block and its predecessor.
+    ///
+    /// Since this is resolved for each block individually, it can be set for
+    /// specific kinds of block-level elements through show-set rules. By
+    /// default, headings use this to get more space above than below them,
+    /// keeping them visually attached to the text they introduce.
+    ///
+    /// ```example
+    /// #show heading: set block(above: 1.6em, below: 0.6em)
+    /// #show figure: set block(above: 2em, below: 2em)
+    ///
+    /// = Results
+    /// Our findings are summarized below.
+    /// #figure(rect(width: 2cm))
+    /// And more text follows.
+    /// ```
     #[parse(
         let spacing = args.named("spacing")?;
         args.named("above")?.or(spacing)
@@ -663,6 +718,8 @@ impl Packed<BlockElem> {
 
         // Fetch/compute these outside of the loop.
         let clip = self.clip(styles);
+        let open_edges = self.open_edges(styles);
+        let count = fragment.len();
         let has_fill_or_stroke = fill.is_some() || stroke.iter().any(Option::is_some);
         let has_inset = !inset.is_zero();
         let is_explicit = matches!(body, None | Some(BlockBody::Content(_)));
@@ -692,19 +749,29 @@ impl Packed<BlockElem> {
                 crate::layout::grow(frame, &inset);
             }
 
+            // Open up the edges where the block continues, if requested.
+            let opened = open_edges.then(|| {
+                let first = i == usize::from(skip_first);
+                let last = i + 1 == count;
+                open_decoration(&stroke, *radius, first, last)
+            });
+            let stroke = opened.as_ref().map_or(&stroke, |(stroke, _)| stroke);
+            let part_radius =
+                || opened.as_ref().map_or_else(|| &*radius, |(_, radius)| radius);
+
             // Clip the contents, if requested.
             if clip {
                 let size = frame.size() + outset.relative_to(frame.size()).sum_by_axis();
-                frame.clip(clip_rect(size, &radius, &stroke));
+                frame.clip(clip_rect(size, part_radius(), stroke));
             }
 
             // Add fill and/or stroke.
             if has_fill_or_stroke && (i > 0 || !skip_first) {
                 frame.fill_and_stroke(
                     fill.clone(),
-                    &stroke,
+                    stroke,
                     &outset,
-                    &radius,
+                    part_radius(),
                     self.span(),
                 );
             }
@@ -721,6 +788,28 @@ impl Packed<BlockElem> {
     }
 }
 
+/// Removes the stroke and corner radius at the edges where a part of a broken
+/// block continues in another region.
+fn open_decoration(
+    stroke: &Sides<Option<FixedStroke>>,
+    mut radius: Corners<Rel<Abs>>,
+    first: bool,
+    last: bool,
+) -> (Sides<Option<FixedStroke>>, Corners<Rel<Abs>>) {
+    let mut stroke = stroke.clone();
+    if !first {
+        stroke.top = None;
+        radius.top_left = Rel::zero();
+        radius.top_right = Rel::zero();
+    }
+    if !last {
+        stroke.bottom = None;
+        radius.bottom_left = Rel::zero();
+        radius.bottom_right = Rel::zero();
+    }
+    (stroke, radius)
+}
+
 /// The contents of a block.
 #[derive(Debug, Clone, PartialEq, Hash)]
 pub enum BlockBody {
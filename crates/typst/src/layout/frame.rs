@@ -1,4 +1,12 @@
 //! Finished documents.
+//!
+//! The result of layout is a tree of [frames](Frame). Each frame has a fixed
+//! size and contains [items](FrameItem) at fixed positions: runs of shaped
+//! [text](TextItem) with their font and glyphs, [shapes](Shape),
+//! [images](Image), [links](Destination), introspection [tags](Tag), and
+//! nested [groups](GroupItem) with a transform and optional clipping. This tree
+//! is all that the exporters in `typst-pdf`, `typst-svg`, and `typst-render`
+//! consume, so other renderers can build on it in the same way.
 
 use std::fmt::{self, Debug, Formatter};
 use std::num::NonZeroUsize;
@@ -147,6 +155,35 @@ impl Frame {
     pub fn items(&self) -> std::slice::Iter<'_, (Point, FrameItem)> {
         self.items.iter()
     }
+
+    /// Calls `f` for every item in this frame and, recursively, in all of its
+    /// groups, in painting order.
+    ///
+    /// Groups themselves are not passed to `f`. Instead, each item is passed
+    /// alongside the transform that maps from its coordinate system into the
+    /// one of this frame. This already accounts for the item's position as
+    /// well as for the transforms of all groups it is nested in. Clipping is
+    /// not applied, so consumers that care about it need to walk the tree
+    /// with [`items`](Self::items) themselves.
+    pub fn visit<F>(&self, mut f: F)
+    where
+        F: FnMut(Transform, &FrameItem),
+    {
+        self.visit_impl(Transform::identity(), &mut f);
+    }
+
+    /// The recursive implementation of `visit`.
+    fn visit_impl(&self, ts: Transform, f: &mut dyn FnMut(Transform, &FrameItem)) {
+        for (pos, item) in self.items() {
+            let ts = ts.pre_concat(Transform::translate(pos.x, pos.y));
+            match item {
+                FrameItem::Group(group) => {
+                    group.frame.visit_impl(ts.pre_concat(group.transform), f)
+                }
+                item => f(ts, item),
+            }
+        }
+    }
 }
 
 /// Insert items and subframes.
@@ -608,3 +645,37 @@ impl From<Position> for Dict {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::Ratio;
+
+    fn square() -> FrameItem {
+        let shape = Geometry::Rect(Size::splat(Abs::pt(1.0))).filled(Color::BLACK.into());
+        FrameItem::Shape(shape, Span::detached())
+    }
+
+    #[test]
+    fn test_visit() {
+        let pt = |x, y| Point::new(Abs::pt(x), Abs::pt(y));
+
+        let mut inner = Frame::soft(Size::splat(Abs::pt(10.0)));
+        inner.push(pt(1.0, 1.0), square());
+        inner.push(pt(2.0, 2.0), square());
+        inner.transform(Transform::scale(Ratio::new(2.0), Ratio::new(2.0)));
+
+        let mut outer = Frame::soft(Size::splat(Abs::pt(30.0)));
+        outer.push(pt(0.0, 0.0), square());
+        outer.push(pt(5.0, 0.0), FrameItem::Group(GroupItem::new(inner)));
+        outer.push(pt(0.0, 3.0), square());
+
+        let mut origins = vec![];
+        outer.visit(|ts, item| {
+            assert!(matches!(item, FrameItem::Shape(..)));
+            origins.push(Point::zero().transform(ts));
+        });
+
+        assert_eq!(origins, [pt(0.0, 0.0), pt(7.0, 2.0), pt(9.0, 4.0), pt(0.0, 3.0)]);
+    }
+}
@@ -114,6 +114,24 @@ use crate::visualize::{Paint, Stroke};
 /// )
 /// ```
 ///
+/// # Parallel texts
+/// Because each row of a grid is as tall as its tallest cell, a grid can keep
+/// two streams of content aligned at defined synchronization points. For
+/// example, a text and its translation can be set side by side with
+/// corresponding paragraphs starting at the same height:
+///
+/// ```example
+/// #let original = ([Veni.], [Vidi. Vici.])
+/// #let translation = ([I came.], [I saw. I conquered.])
+///
+/// #grid(
+///   columns: (1fr, 1fr),
+///   column-gutter: 1em,
+///   row-gutter: 0.8em,
+///   ..original.zip(translation).flatten(),
+/// )
+/// ```
+///
 /// # Styling the grid
 /// The grid's appearance can be customized through different parameters. These
 /// are the most important ones:
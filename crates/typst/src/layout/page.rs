@@ -13,7 +13,7 @@ use crate::foundations::{
 };
 use crate::layout::{
     Abs, Alignment, FlushElem, Frame, HAlignment, Length, OuterVAlignment, Ratio, Rel,
-    Sides, SpecificAlignment,
+    Sides, Size, SpecificAlignment,
 };
 use crate::model::Numbering;
 use crate::utils::{singleton, NonZeroExt, Scalar};
@@ -74,6 +74,15 @@ pub struct PageElem {
     /// by inserting a [page break]($pagebreak). Most examples throughout this
     /// documentation use `{auto}` for the height of the page to dynamically
     /// grow and shrink to fit their content.
+    ///
+    /// Combined with an `{auto}` width and zero margins, this yields pages
+    /// that are tightly cropped to their content. This is useful to export
+    /// individual elements like formulas or diagrams as images.
+    ///
+    /// ```example
+    /// #set page(width: auto, height: auto, margin: 0pt)
+    /// $ integral_0^1 x^2 dif x = 1/3 $
+    /// ```
     #[resolve]
     #[parse(
         args.named("height")?
@@ -202,6 +211,52 @@ pub struct PageElem {
     #[ghost]
     pub fill: Smart<Option<Paint>>,
 
+    /// How far the page extends beyond its trimmed size on each side.
+    ///
+    /// Professional printers typically print onto larger sheets and cut them
+    /// down to the final size afterwards. To avoid white slivers at the edges,
+    /// backgrounds and images that touch the edge of the page should extend
+    /// into this _bleed_ area. The configured page size is the final, trimmed
+    /// size and positions on the page are still measured from its top-left
+    /// corner; only the exported page grows by the bleed on each side. In PDF
+    /// export, the trimmed and bleed areas are recorded as the page's
+    /// `TrimBox` and `BleedBox`.
+    ///
+    /// ```example
+    /// #set page(
+    ///   width: 3cm,
+    ///   height: 2cm,
+    ///   bleed: 3mm,
+    ///   fill: aqua,
+    /// )
+    ///
+    /// Bleeding.
+    /// ```
+    #[resolve]
+    #[ghost]
+    pub bleed: Length,
+
+    /// Whether to draw crop marks at the corners of the trimmed page.
+    ///
+    /// The marks are drawn just outside of the [bleed]($page.bleed) area, so
+    /// that they are not covered by backgrounds, and indicate where the
+    /// printed sheet should be cut. The exported page grows to make room for
+    /// them. They have no effect if the bleed is zero.
+    ///
+    /// ```example
+    /// #set page(
+    ///   width: 3cm,
+    ///   height: 2cm,
+    ///   bleed: 3mm,
+    ///   crop-marks: true,
+    /// )
+    ///
+    /// Ready for print.
+    /// ```
+    #[default(false)]
+    #[ghost]
+    pub crop_marks: bool,
+
     /// How to [number]($numbering) the pages.
     ///
     /// If an explicit `footer` (or `header` for top-aligned numbering) is
@@ -311,7 +366,9 @@ pub struct PageElem {
     /// Content in the page's background.
     ///
     /// This content will be placed behind the page's body. It can be
-    /// used to place a background image or a watermark.
+    /// used to place a background image or a watermark. If the page has a
+    /// [bleed]($page.bleed), the background spans the full page including the
+    /// bleed area.
     ///
     /// ```example
     /// #set page(background: rotate(24deg,
@@ -432,7 +489,7 @@ impl PagebreakElem {
 }
 
 /// A finished page.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Hash)]
 pub struct Page {
     /// The frame that defines the page.
     pub frame: Frame,
@@ -450,6 +507,14 @@ pub struct Page {
     /// The logical page number (controlled by `counter(page)` and may thus not
     /// match the physical number).
     pub number: usize,
+    /// How far the bleed area extends beyond the trimmed page on each side.
+    ///
+    /// The frame has the size of the trimmed page. Content in the bleed area
+    /// is placed at negative coordinates or beyond the frame's size.
+    pub bleed: Abs,
+    /// How far the exported page extends beyond the bleed area on each side to
+    /// make room for printer's marks.
+    pub slug: Abs,
 }
 
 impl Page {
@@ -466,6 +531,18 @@ impl Page {
     pub fn fill_or_white(&self) -> Option<Paint> {
         self.fill.clone().unwrap_or_else(|| Some(Color::WHITE.into()))
     }
+
+    /// The size of the page including its bleed area.
+    pub fn bleed_size(&self) -> Size {
+        self.frame.size() + Size::splat(2.0 * self.bleed)
+    }
+
+    /// The size of the exported page, including its bleed area and slug.
+    ///
+    /// The trimmed page sits at an offset of `bleed + slug` within it.
+    pub fn media_size(&self) -> Size {
+        self.frame.size() + Size::splat(2.0 * (self.bleed + self.slug))
+    }
 }
 
 /// Specification of the page's margins.
@@ -2,7 +2,9 @@ use super::LayoutedPage;
 use crate::diag::SourceResult;
 use crate::engine::Engine;
 use crate::introspection::{ManualPageCounter, Tag};
-use crate::layout::{Frame, FrameItem, Page, Point};
+use crate::layout::{Abs, Frame, FrameItem, Page, Point, Size};
+use crate::syntax::Span;
+use crate::visualize::{Color, FixedStroke, Geometry};
 
 /// Piece together the inner page frame and the marginals. We can only do this
 /// at the very end because inside/outside margins require knowledge of the
@@ -22,6 +24,8 @@ pub fn finalize(
         foreground,
         fill,
         numbering,
+        bleed,
+        crop_marks,
     }: LayoutedPage,
 ) -> SourceResult<Page> {
     // If two sided, left becomes inside and right becomes outside.
@@ -31,8 +35,11 @@ pub fn finalize(
         std::mem::swap(&mut margin.left, &mut margin.right);
     }
 
-    // Create a frame for the full page.
-    let mut frame = Frame::hard(inner.size() + margin.sum_by_axis());
+    // Create a frame for the trimmed page. Everything in the bleed area and
+    // the slug lies outside of it, so that positions on the page are
+    // unaffected by the bleed.
+    let trim = inner.size() + margin.sum_by_axis();
+    let mut frame = Frame::hard(trim);
 
     // Add tags.
     for tag in tags.drain(..) {
@@ -41,9 +48,10 @@ pub fn finalize(
 
     // Add the "before" marginals. The order in which we push things here is
     // important as it affects the relative ordering of introspectible elements
-    // and thus how counters resolve.
+    // and thus how counters resolve. The background covers the bleed area, so
+    // it starts at the corner of the bleed area.
     if let Some(background) = background {
-        frame.push_frame(Point::zero(), background);
+        frame.push_frame(Point::splat(-bleed), background);
     }
     if let Some(header) = header {
         frame.push_frame(Point::with_x(margin.left), header);
@@ -54,13 +62,19 @@ pub fn finalize(
 
     // Add the "after" marginals.
     if let Some(footer) = footer {
-        let y = frame.height() - footer.height();
+        let y = trim.y - footer.height();
         frame.push_frame(Point::new(margin.left, y), footer);
     }
     if let Some(foreground) = foreground {
         frame.push_frame(Point::zero(), foreground);
     }
 
+    let mut slug = Abs::zero();
+    if crop_marks && bleed > Abs::zero() {
+        add_crop_marks(&mut frame, trim, bleed);
+        slug = Abs::pt(CROP_MARK_LENGTH);
+    }
+
     // Apply counter updates from within the page to the manual page counter.
     counter.visit(engine, &frame)?;
 
@@ -68,5 +82,31 @@ pub fn finalize(
     let number = counter.logical();
     counter.step();
 
-    Ok(Page { frame, fill, numbering, number })
+    Ok(Page { frame, fill, numbering, number, bleed, slug })
+}
+
+/// How far crop marks extend beyond the bleed area, in points.
+const CROP_MARK_LENGTH: f64 = 12.0;
+
+/// Draw crop marks at the corners of the trimmed page, just outside of its
+/// bleed area.
+fn add_crop_marks(frame: &mut Frame, trim: Size, bleed: Abs) {
+    let stroke = FixedStroke::from_pair(Color::BLACK, Abs::pt(0.25));
+    let len = Abs::pt(CROP_MARK_LENGTH);
+    for (x, sx) in [(Abs::zero(), -1.0), (trim.x, 1.0)] {
+        for (y, sy) in [(Abs::zero(), -1.0), (trim.y, 1.0)] {
+            let horizontal =
+                Geometry::Line(Point::with_x(sx * len)).stroked(stroke.clone());
+            let vertical =
+                Geometry::Line(Point::with_y(sy * len)).stroked(stroke.clone());
+            frame.push(
+                Point::new(x + sx * bleed, y),
+                FrameItem::Shape(horizontal, Span::detached()),
+            );
+            frame.push(
+                Point::new(x, y + sy * bleed),
+                FrameItem::Shape(vertical, Span::detached()),
+            );
+        }
+    }
 }
@@ -33,6 +33,8 @@ pub struct LayoutedPage {
     pub foreground: Option<Frame>,
     pub fill: Smart<Option<Paint>>,
     pub numbering: Option<Numbering>,
+    pub bleed: Abs,
+    pub crop_marks: bool,
 }
 
 /// Layout a single page suitable  for parity adjustment.
@@ -122,6 +124,8 @@ fn layout_page_run_impl(
     let header_ascent = PageElem::header_ascent_in(styles).relative_to(margin.top);
     let footer_descent = PageElem::footer_descent_in(styles).relative_to(margin.bottom);
     let numbering = PageElem::numbering_in(styles);
+    let bleed = PageElem::bleed_in(styles).max(Abs::zero());
+    let crop_marks = PageElem::crop_marks_in(styles);
     let number_align = PageElem::number_align_in(styles);
     let binding =
         PageElem::binding_in(styles).unwrap_or_else(|| match TextElem::dir_in(styles) {
@@ -193,6 +197,7 @@ fn layout_page_run_impl(
         let header_size = Size::new(inner.width(), margin.top - header_ascent);
         let footer_size = Size::new(inner.width(), margin.bottom - footer_descent);
         let full_size = inner.size() + margin.sum_by_axis();
+        let bleed_size = full_size + Size::splat(2.0 * bleed);
         let mid = HAlignment::Center + VAlignment::Horizon;
         layouted.push(LayoutedPage {
             inner,
@@ -200,11 +205,13 @@ fn layout_page_run_impl(
             numbering: numbering.clone(),
             header: layout_marginal(header, header_size, Alignment::BOTTOM)?,
             footer: layout_marginal(footer, footer_size, Alignment::TOP)?,
-            background: layout_marginal(background, full_size, mid)?,
+            background: layout_marginal(background, bleed_size, mid)?,
             foreground: layout_marginal(foreground, full_size, mid)?,
             margin,
             binding,
             two_sided,
+            bleed,
+            crop_marks,
         });
     }
 
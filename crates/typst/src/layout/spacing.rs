@@ -92,6 +92,22 @@ pub struct VElem {
     /// collapse. Weak spacings will always collapse adjacent paragraph spacing,
     /// even if the paragraph spacing is larger.
     ///
+    /// Since the start of a flow also includes the top of each page or column
+    /// after a break, weak spacing never produces blank space there. This
+    /// makes it a good fit for templates that want to add space before
+    /// elements like headings.
+    ///
+    /// When adjacent spacings collapse, the one with the higher precedence
+    /// wins, regardless of its size:
+    ///
+    /// 1. Weak spacing inserted with this function
+    /// 2. The [`above`]($block.above) and [`below`]($block.below) spacing of
+    ///    blocks when it is set explicitly
+    /// 3. Paragraph [spacing]($par.spacing) and automatic block spacing
+    ///
+    /// Between spacings of the same precedence, the largest one is kept.
+    /// Spacing that is not weak never collapses.
+    ///
     /// ```example
     /// The following theorem is
     /// foundational to the field:
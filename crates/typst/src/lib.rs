@@ -68,10 +68,11 @@ use crate::diag::{
 };
 use crate::engine::{Engine, Route, Sink, Traced};
 use crate::foundations::{
-    Array, Bytes, Datetime, Dict, Module, Scope, StyleChain, Styles, Value,
+    Array, Bytes, Content, Datetime, Dict, Module, Scope, Smart, StyleChain, Styles,
+    Value,
 };
-use crate::introspection::Introspector;
-use crate::layout::{Alignment, Dir};
+use crate::introspection::{Introspector, Locator};
+use crate::layout::{Abs, Alignment, Axes, Dir, Frame, Page, Region};
 use crate::model::Document;
 use crate::syntax::package::PackageSpec;
 use crate::syntax::{FileId, Source, Span};
@@ -79,15 +80,34 @@ use crate::text::{Font, FontBook};
 use crate::utils::LazyHash;
 use crate::visualize::Color;
 
+/// The maximum number of layout passes used by [`compile`] and
+/// [`compile_snippet`].
+pub const DEFAULT_LAYOUT_PASSES: usize = 5;
+
 /// Compile sources into a fully layouted document.
 ///
 /// - Returns `Ok(document)` if there were no fatal errors.
 /// - Returns `Err(errors)` if there were fatal errors.
 #[typst_macros::time]
 pub fn compile(world: &dyn World) -> Warned<SourceResult<Document>> {
+    compile_with_passes(world, DEFAULT_LAYOUT_PASSES)
+}
+
+/// Compile sources into a fully layouted document, giving up on stabilizing
+/// introspections after the given number of layout passes.
+///
+/// If the document did not converge within `passes`, the last layout is
+/// returned together with a warning that points to an element that was still
+/// changing.
+#[typst_macros::time]
+pub fn compile_with_passes(
+    world: &dyn World,
+    passes: usize,
+) -> Warned<SourceResult<Document>> {
     let mut sink = Sink::new();
-    let output = compile_impl(world.track(), Traced::default().track(), &mut sink)
-        .map_err(deduplicate);
+    let output =
+        compile_impl(world.track(), Traced::default().track(), &mut sink, passes)
+            .map_err(deduplicate);
     Warned { output, warnings: sink.warnings() }
 }
 
@@ -97,7 +117,7 @@ pub fn compile(world: &dyn World) -> Warned<SourceResult<Document>> {
 pub fn trace(world: &dyn World, span: Span) -> EcoVec<(Value, Option<Styles>)> {
     let mut sink = Sink::new();
     let traced = Traced::new(span);
-    compile_impl(world.track(), traced.track(), &mut sink).ok();
+    compile_impl(world.track(), traced.track(), &mut sink, DEFAULT_LAYOUT_PASSES).ok();
     sink.values()
 }
 
@@ -107,37 +127,169 @@ fn compile_impl(
     world: Tracked<dyn World + '_>,
     traced: Tracked<Traced>,
     sink: &mut Sink,
+    passes: usize,
 ) -> SourceResult<Document> {
     let library = world.library();
     let styles = StyleChain::new(&library.styles);
 
+    // First evaluate the main source file into a module.
+    let content = eval_main(world, traced, sink)?;
+
+    converge(world, traced, sink, passes, |engine| {
+        crate::layout::layout_document(engine, &content, styles)
+    })
+}
+
+/// Compiles content into a single frame that is tightly sized to it.
+///
+/// In contrast to [`compile`], this does not lay out any pages. Instead, the
+/// content is laid out into an infinitely large region, so that the resulting
+/// frame is exactly as large as the content. This is useful for embedders that
+/// want to render small fragments like a formula without setting up a page.
+/// The content can for instance be produced with
+/// [`eval_string`](eval::eval_string).
+///
+/// - Returns `Ok(frame)` if there were no fatal errors.
+/// - Returns `Err(errors)` if there were fatal errors.
+#[typst_macros::time]
+pub fn compile_snippet(
+    world: &dyn World,
+    content: &Content,
+) -> Warned<SourceResult<Frame>> {
+    let mut sink = Sink::new();
+    let output = compile_snippet_impl(
+        world.track(),
+        Traced::default().track(),
+        &mut sink,
+        content,
+        DEFAULT_LAYOUT_PASSES,
+    )
+    .map_err(deduplicate);
+    Warned { output, warnings: sink.warnings() }
+}
+
+/// Compiles the main source file into a single frame that is tightly sized to
+/// its content.
+///
+/// This works like [`compile_snippet`], but the content is obtained by
+/// evaluating the world's main source file. Since no pages are laid out, the
+/// file must not configure pages.
+///
+/// - Returns `Ok(frame)` if there were no fatal errors.
+/// - Returns `Err(errors)` if there were fatal errors.
+#[typst_macros::time]
+pub fn compile_main_snippet(world: &dyn World) -> Warned<SourceResult<Frame>> {
+    compile_main_snippet_with_passes(world, DEFAULT_LAYOUT_PASSES)
+}
+
+/// Compiles the main source file into a single frame that is tightly sized to
+/// its content, giving up on stabilizing introspections after the given number
+/// of layout passes.
+///
+/// See [`compile_main_snippet`] and [`compile_with_passes`] for details.
+#[typst_macros::time]
+pub fn compile_main_snippet_with_passes(
+    world: &dyn World,
+    passes: usize,
+) -> Warned<SourceResult<Frame>> {
+    let mut sink = Sink::new();
+    let traced = Traced::default();
+    let output = eval_main(world.track(), traced.track(), &mut sink)
+        .and_then(|content| {
+            compile_snippet_impl(
+                world.track(),
+                traced.track(),
+                &mut sink,
+                &content,
+                passes,
+            )
+        })
+        .map_err(deduplicate);
+    Warned { output, warnings: sink.warnings() }
+}
+
+/// Evaluates the world's main source file into content.
+fn eval_main(
+    world: Tracked<dyn World + '_>,
+    traced: Tracked<Traced>,
+    sink: &mut Sink,
+) -> SourceResult<Content> {
     // Fetch the main source file once.
     let main = world.main();
     let main = world
         .source(main)
         .map_err(|err| hint_invalid_main_file(world, err, main))?;
 
-    // First evaluate the main source file into a module.
-    let content = crate::eval::eval(
+    Ok(crate::eval::eval(
         world,
         traced,
         sink.track_mut(),
         Route::default().track(),
         &main,
     )?
-    .content();
+    .content())
+}
 
+/// The internal implementation of `compile_snippet`.
+fn compile_snippet_impl(
+    world: Tracked<dyn World + '_>,
+    traced: Tracked<Traced>,
+    sink: &mut Sink,
+    content: &Content,
+    passes: usize,
+) -> SourceResult<Frame> {
+    let library = world.library();
+    let styles = StyleChain::new(&library.styles);
+    let region = Region::new(Axes::splat(Abs::inf()), Axes::splat(false));
+
+    let document = converge(world, traced, sink, passes, |engine| {
+        let frame = crate::layout::layout_frame(
+            engine,
+            content,
+            Locator::root(),
+            styles,
+            region,
+        )?;
+
+        // Wrap the frame into a page so that the introspector can find the
+        // elements in it.
+        let page = Page {
+            frame,
+            fill: Smart::Auto,
+            numbering: None,
+            number: 1,
+            bleed: Abs::zero(),
+            slug: Abs::zero(),
+        };
+
+        Ok(Document { pages: vec![page], ..Document::default() })
+    })?;
+
+    Ok(document.pages.into_iter().next().unwrap().frame)
+}
+
+/// Repeatedly lays out with the given function until all introspections
+/// stabilize or the maximum number of `passes` is reached.
+fn converge(
+    world: Tracked<dyn World + '_>,
+    traced: Tracked<Traced>,
+    sink: &mut Sink,
+    passes: usize,
+    mut layout: impl FnMut(&mut Engine) -> SourceResult<Document>,
+) -> SourceResult<Document> {
+    let passes = passes.max(1);
     let mut iter = 0;
     let mut subsink;
     let mut document = Document::default();
 
     // Relayout until all introspections stabilize.
-    // If that doesn't happen within five attempts, we give up.
+    // If that doesn't happen within the given number of passes, we give up.
     loop {
         // The name of the iterations for timing scopes.
         const ITER_NAMES: &[&str] =
             &["layout (1)", "layout (2)", "layout (3)", "layout (4)", "layout (5)"];
-        let _scope = TimingScope::new(ITER_NAMES[iter], None);
+        let name = ITER_NAMES.get(iter).copied().unwrap_or("layout (n)");
+        let _scope = TimingScope::new(name, None);
 
         subsink = Sink::new();
 
@@ -151,7 +303,8 @@ fn compile_impl(
         };
 
         // Layout!
-        document = crate::layout::layout_document(&mut engine, &content, styles)?;
+        let output = layout(&mut engine)?;
+        let previous = std::mem::replace(&mut document, output);
         document.introspector.rebuild(&document.pages);
         iter += 1;
 
@@ -159,11 +312,12 @@ fn compile_impl(
             break;
         }
 
-        if iter >= 5 {
-            subsink.warn(warning!(
-                Span::detached(), "layout did not converge within 5 attempts";
-                hint: "check if any states or queries are updating themselves"
-            ));
+        if iter >= passes {
+            // Only compare with the previous pass if there was one.
+            let elem = (iter > 1)
+                .then(|| document.introspector.first_difference(&previous.introspector))
+                .flatten();
+            subsink.warn(unstable_warning(elem, passes));
             break;
         }
     }
@@ -179,6 +333,24 @@ fn compile_impl(
     Ok(document)
 }
 
+/// Builds the warning for a layout that did not converge, pointing to the
+/// element that still changed in the last pass, if any.
+fn unstable_warning(elem: Option<&Content>, passes: usize) -> SourceDiagnostic {
+    let attempts = if passes == 1 { "attempt" } else { "attempts" };
+    let Some(elem) = elem else {
+        return warning!(
+            Span::detached(), "layout did not converge within {passes} {attempts}";
+            hint: "check if any states or queries are updating themselves"
+        );
+    };
+
+    warning!(
+        elem.span(), "layout did not converge within {passes} {attempts}";
+        hint: "this may be caused by the `{}` element changing between layout passes", elem.func().name();
+        hint: "check if any states or queries are updating themselves"
+    )
+}
+
 /// Deduplicate diagnostics.
 fn deduplicate(mut diags: EcoVec<SourceDiagnostic>) -> EcoVec<SourceDiagnostic> {
     let mut unique = HashSet::new();
@@ -54,7 +54,7 @@ pub enum Readable {
 }
 
 impl Readable {
-    fn as_slice(&self) -> &[u8] {
+    pub(crate) fn as_slice(&self) -> &[u8] {
         match self {
             Readable::Bytes(v) => v,
             Readable::Str(v) => v.as_bytes(),
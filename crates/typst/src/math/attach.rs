@@ -1,11 +1,13 @@
+use ecow::EcoString;
 use unicode_math_class::MathClass;
 
 use crate::diag::SourceResult;
-use crate::foundations::{elem, Content, Packed, Smart, StyleChain};
+use crate::foundations::{elem, Content, Packed, PlainText, Smart, StyleChain};
 use crate::layout::{Abs, Axis, Corner, Frame, Length, Point, Rel, Size};
 use crate::math::{
-    stretch_fragment, style_for_subscript, style_for_superscript, EquationElem,
-    FrameFragment, LayoutMath, MathContext, MathFragment, MathSize, Scaled, StretchElem,
+    plain_text_group, stretch_fragment, style_for_subscript, style_for_superscript,
+    EquationElem, FrameFragment, LayoutMath, MathContext, MathFragment, MathSize, Scaled,
+    StretchElem,
 };
 use crate::text::TextElem;
 use crate::utils::OptionExt;
@@ -24,7 +26,7 @@ macro_rules! measure {
 ///   tl: 1, tr: 2+3, bl: 4+5, br: 6,
 /// ) $
 /// ```
-#[elem(LayoutMath)]
+#[elem(LayoutMath, PlainText)]
 pub struct AttachElem {
     /// The base to which things are attached.
     #[required]
@@ -56,6 +58,26 @@ pub struct AttachElem {
     pub br: Option<Content>,
 }
 
+impl PlainText for Packed<AttachElem> {
+    fn plain_text(&self, text: &mut EcoString) {
+        fn attach(text: &mut EcoString, attachment: Option<Content>, marker: char) {
+            if let Some(attachment) = attachment {
+                text.push(marker);
+                plain_text_group(&attachment, text);
+            }
+        }
+
+        let styles = StyleChain::default();
+        attach(text, self.tl(styles), '^');
+        attach(text, self.bl(styles), '_');
+        text.push_str(&self.base().plain_text());
+        attach(text, self.t(styles), '^');
+        attach(text, self.b(styles), '_');
+        attach(text, self.tr(styles), '^');
+        attach(text, self.br(styles), '_');
+    }
+}
+
 impl LayoutMath for Packed<AttachElem> {
     #[typst_macros::time(name = "math.attach", span = self.span())]
     fn layout_math(&self, ctx: &mut MathContext, styles: StyleChain) -> SourceResult<()> {
@@ -103,6 +103,28 @@ pub struct EquationElem {
     /// ```
     pub supplement: Smart<Option<Supplement>>,
 
+    /// A custom tag to display instead of the equation's number.
+    ///
+    /// The tag is placed where the number would be, following the
+    /// [`number-align`]($math.equation.number-align). A tagged equation does
+    /// not step the equation counter and references to it show the tag. This
+    /// only has an effect on block-level equations.
+    ///
+    /// ```example
+    /// #set math.equation(numbering: "(1)")
+    ///
+    /// $ a + b = c $
+    /// #math.equation(
+    ///   block: true,
+    ///   tag: [(1a)],
+    ///   $ a = c - b $,
+    /// ) <rearranged>
+    /// $ b = c - a $
+    ///
+    /// See @rearranged.
+    /// ```
+    pub tag: Option<Content>,
+
     /// The contents of the equation.
     #[required]
     pub body: Content,
@@ -155,7 +177,12 @@ impl Synthesize for Packed<EquationElem> {
             }
         };
 
+        // Resolve the tag so that counting, outlining, and referencing see the
+        // one that applies to this equation.
+        let tag = self.tag(styles);
+
         self.push_supplement(Smart::Custom(Some(Supplement::Content(supplement))));
+        self.push_tag(tag);
         Ok(())
     }
 }
@@ -194,8 +221,10 @@ impl ShowSet for Packed<EquationElem> {
 
 impl Count for Packed<EquationElem> {
     fn update(&self) -> Option<CounterUpdate> {
-        (self.block(StyleChain::default()) && self.numbering().is_some())
-            .then(|| CounterUpdate::Step(NonZeroUsize::ONE))
+        (self.block(StyleChain::default())
+            && self.numbering().is_some()
+            && self.tag(StyleChain::default()).is_none())
+        .then(|| CounterUpdate::Step(NonZeroUsize::ONE))
     }
 }
 
@@ -230,8 +259,16 @@ impl Outlinable for Packed<EquationElem> {
         if !self.block(StyleChain::default()) {
             return Ok(None);
         }
-        let Some(numbering) = self.numbering() else {
-            return Ok(None);
+
+        let numbers = match (self.tag(StyleChain::default()), self.numbering()) {
+            (Some(tag), _) => tag,
+            (None, Some(numbering)) => self.counter().display_at_loc(
+                engine,
+                self.location().unwrap(),
+                styles,
+                numbering,
+            )?,
+            (None, None) => return Ok(None),
         };
 
         // After synthesis, this should always be custom content.
@@ -244,13 +281,6 @@ impl Outlinable for Packed<EquationElem> {
             supplement += TextElem::packed("\u{a0}");
         }
 
-        let numbers = self.counter().display_at_loc(
-            engine,
-            self.location().unwrap(),
-            styles,
-            numbering,
-        )?;
-
         Ok(Some(supplement + numbers))
     }
 }
@@ -383,19 +413,26 @@ fn layout_equation_block(
         vec![full_equation_builder]
     };
 
-    let Some(numbering) = (**elem).numbering(styles) else {
-        let frames = equation_builders
-            .into_iter()
-            .map(MathRunFrameBuilder::build)
-            .collect();
-        return Ok(Fragment::frames(frames));
+    let tag = match (elem.tag(styles), (**elem).numbering(styles)) {
+        (Some(tag), _) => tag,
+        (None, Some(numbering)) => Counter::of(EquationElem::elem()).display_at_loc(
+            engine,
+            elem.location().unwrap(),
+            styles,
+            numbering,
+        )?,
+        (None, None) => {
+            let frames = equation_builders
+                .into_iter()
+                .map(MathRunFrameBuilder::build)
+                .collect();
+            return Ok(Fragment::frames(frames));
+        }
     };
 
     let pod = Region::new(regions.base(), Axes::splat(false));
-    let counter = Counter::of(EquationElem::elem())
-        .display_at_loc(engine, elem.location().unwrap(), styles, numbering)?
-        .spanned(span);
-    let number = layout_frame(engine, &counter, locator.next(&()), styles, pod)?;
+    let number =
+        layout_frame(engine, &tag.spanned(span), locator.next(&()), styles, pod)?;
 
     static NUMBER_GUTTER: Em = Em::new(0.5);
     let full_number_width = number.width() + NUMBER_GUTTER.resolve(styles);
@@ -1,9 +1,14 @@
+use ecow::EcoString;
+
 use crate::diag::{bail, SourceResult};
-use crate::foundations::{elem, Content, Packed, StyleChain, Value};
+use crate::foundations::{
+    elem, Cast, Content, NativeElement, Packed, PlainText, StyleChain, Value,
+};
 use crate::layout::{Em, Frame, FrameItem, Point, Size};
 use crate::math::{
-    scaled_font_size, style_for_denominator, style_for_numerator, FrameFragment,
-    GlyphFragment, LayoutMath, MathContext, Scaled, DELIM_SHORT_FALL,
+    is_atomic, plain_text_group, scaled_font_size, style_for_denominator,
+    style_for_numerator, FrameFragment, GlyphFragment, LayoutMath, LrElem, MathContext,
+    Scaled, DELIM_SHORT_FALL,
 };
 use crate::syntax::{Span, Spanned};
 use crate::text::TextElem;
@@ -24,7 +29,7 @@ const FRAC_AROUND: Em = Em::new(0.1);
 /// expressions into a fraction. Multiple atoms can be grouped into a single
 /// expression using round grouping parenthesis. Such parentheses are removed
 /// from the output, but you can nest multiple to force them.
-#[elem(title = "Fraction", LayoutMath)]
+#[elem(title = "Fraction", LayoutMath, PlainText)]
 pub struct FracElem {
     /// The fraction's numerator.
     #[required]
@@ -33,19 +38,68 @@ pub struct FracElem {
     /// The fraction's denominator.
     #[required]
     pub denom: Content,
+
+    /// How the numerator and denominator are arranged.
+    ///
+    /// ```example
+    /// $ frac(a, b) quad
+    ///   frac(a, b, style: "skewed") quad
+    ///   frac(a, b, style: "horizontal") $
+    /// ```
+    #[default(FracStyle::Vertical)]
+    pub style: FracStyle,
+}
+
+/// How a [fraction]($math.frac) is arranged.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum FracStyle {
+    /// The numerator is stacked on top of the denominator, separated by a
+    /// horizontal line.
+    Vertical,
+    /// The numerator is raised and the denominator lowered, separated by a
+    /// slash. This is compact enough for inline use.
+    Skewed,
+    /// The numerator and denominator are set next to each other on the
+    /// baseline, separated by a slash.
+    Horizontal,
+}
+
+impl PlainText for Packed<FracElem> {
+    fn plain_text(&self, text: &mut EcoString) {
+        plain_text_group(self.num(), text);
+        text.push('/');
+        plain_text_group(self.denom(), text);
+    }
 }
 
 impl LayoutMath for Packed<FracElem> {
     #[typst_macros::time(name = "math.frac", span = self.span())]
     fn layout_math(&self, ctx: &mut MathContext, styles: StyleChain) -> SourceResult<()> {
-        layout(
-            ctx,
-            styles,
-            self.num(),
-            std::slice::from_ref(self.denom()),
-            false,
-            self.span(),
-        )
+        match self.style(styles) {
+            FracStyle::Vertical => layout(
+                ctx,
+                styles,
+                self.num(),
+                std::slice::from_ref(self.denom()),
+                false,
+                self.span(),
+            ),
+            FracStyle::Skewed => {
+                layout_skewed(ctx, styles, self.num(), self.denom(), self.span())
+            }
+            FracStyle::Horizontal => {
+                let frame = ctx.layout_into_frame(
+                    &Content::sequence([
+                        group(self.num()),
+                        TextElem::packed('/'),
+                        group(self.denom()),
+                    ]),
+                    styles,
+                )?;
+                ctx.push(FrameFragment::new(ctx, styles, frame));
+                Ok(())
+            }
+        }
     }
 }
 
@@ -176,3 +230,74 @@ fn layout(
 
     Ok(())
 }
+
+/// Wraps an operand of a horizontal fraction in parentheses unless it is
+/// atomic, since the parser already removed any grouping parentheses.
+fn group(content: &Content) -> Content {
+    if is_atomic(content) {
+        return content.clone();
+    }
+
+    LrElem::new(Content::sequence([
+        TextElem::packed('('),
+        content.clone(),
+        TextElem::packed(')'),
+    ]))
+    .pack()
+    .spanned(content.span())
+}
+
+/// Layout a skewed fraction.
+fn layout_skewed(
+    ctx: &mut MathContext,
+    styles: StyleChain,
+    num: &Content,
+    denom: &Content,
+    span: Span,
+) -> SourceResult<()> {
+    let font_size = scaled_font_size(ctx, styles);
+    let short_fall = DELIM_SHORT_FALL.at(font_size);
+    let axis = scaled!(ctx, styles, axis_height);
+    let h_gap = scaled!(ctx, styles, skewed_fraction_horizontal_gap);
+    let v_gap = scaled!(ctx, styles, skewed_fraction_vertical_gap);
+
+    let num_style = style_for_numerator(styles);
+    let num = ctx.layout_into_frame(num, styles.chain(&num_style))?;
+
+    let denom_style = style_for_denominator(styles);
+    let denom = ctx.layout_into_frame(denom, styles.chain(&denom_style))?;
+
+    // The numerator ends above and the denominator starts below the axis,
+    // with the vertical gap between them.
+    let num_shift = axis + v_gap / 2.0 + num.descent();
+    let denom_shift = denom.ascent() - axis + v_gap / 2.0;
+    let ascent = num_shift + num.ascent();
+    let descent = denom_shift + denom.descent();
+
+    let mut slash = GlyphFragment::new(ctx, styles, '/', span).stretch_vertical(
+        ctx,
+        ascent + descent,
+        short_fall,
+    );
+    slash.center_on_axis(ctx);
+    let slash = slash.frame;
+
+    let top = ascent.max(slash.ascent());
+    let bottom = descent.max(slash.descent());
+    let gap = h_gap.max(slash.width());
+    let width = num.width() + gap + denom.width();
+
+    let num_pos = Point::with_y(top - ascent);
+    let slash_pos =
+        Point::new(num.width() + (gap - slash.width()) / 2.0, top - slash.ascent());
+    let denom_pos = Point::new(num.width() + gap, top + denom_shift - denom.ascent());
+
+    let mut frame = Frame::soft(Size::new(width, top + bottom));
+    frame.set_baseline(top);
+    frame.push_frame(num_pos, num);
+    frame.push_frame(slash_pos, slash);
+    frame.push_frame(denom_pos, denom);
+    ctx.push(FrameFragment::new(ctx, styles, frame));
+
+    Ok(())
+}
@@ -252,6 +252,81 @@ pub fn norm(
     delimited(body, '‖', '‖', size)
 }
 
+/// A bra in Dirac notation.
+///
+/// ```example
+/// $ bra(psi) $
+/// ```
+#[func]
+pub fn bra(
+    /// The size of the brackets, relative to the height of the wrapped content.
+    #[named]
+    size: Option<Smart<Rel<Length>>>,
+    /// The state to put into the bra.
+    body: Content,
+) -> Content {
+    delimited(body, '⟨', '|', size)
+}
+
+/// A ket in Dirac notation.
+///
+/// ```example
+/// $ ket(psi) $
+/// ```
+#[func]
+pub fn ket(
+    /// The size of the brackets, relative to the height of the wrapped content.
+    #[named]
+    size: Option<Smart<Rel<Length>>>,
+    /// The state to put into the ket.
+    body: Content,
+) -> Content {
+    delimited(body, '|', '⟩', size)
+}
+
+/// The inner product of two states in Dirac notation.
+///
+/// The bar between the states scales along with the angle brackets.
+///
+/// ```example
+/// $ braket(phi, psi) $
+/// $ braket(phi, H/2, psi) $
+/// ```
+#[func]
+pub fn braket(
+    /// The size of the brackets, relative to the height of the wrapped content.
+    #[named]
+    size: Option<Smart<Rel<Length>>>,
+    /// The states and operators between the brackets, separated by bars.
+    #[variadic]
+    parts: Vec<Content>,
+) -> Content {
+    let mut body = Content::empty();
+    for (i, part) in parts.into_iter().enumerate() {
+        if i > 0 {
+            body += MidElem::new(TextElem::packed('|')).pack();
+        }
+        body += part;
+    }
+    delimited(body, '⟨', '⟩', size)
+}
+
+/// The expectation value of an operator.
+///
+/// ```example
+/// $ expval(x^2) $
+/// ```
+#[func]
+pub fn expval(
+    /// The size of the brackets, relative to the height of the wrapped content.
+    #[named]
+    size: Option<Smart<Rel<Length>>>,
+    /// The operator to take the expectation value of.
+    body: Content,
+) -> Content {
+    delimited(body, '⟨', '⟩', size)
+}
+
 fn delimited(
     body: Content,
     left: char,
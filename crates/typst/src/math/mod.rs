@@ -16,6 +16,7 @@ mod fragment;
 mod lr;
 mod matrix;
 mod op;
+mod phantom;
 mod root;
 mod row;
 mod spacing;
@@ -33,6 +34,7 @@ pub use self::frac::*;
 pub use self::lr::*;
 pub use self::matrix::*;
 pub use self::op::*;
+pub use self::phantom::*;
 pub use self::root::*;
 pub use self::stretch::*;
 pub use self::style::*;
@@ -43,8 +45,10 @@ use self::fragment::*;
 use self::row::*;
 use self::spacing::*;
 
+use ecow::EcoString;
+
 use crate::diag::SourceResult;
-use crate::foundations::{category, Category, Module, Scope, StyleChain};
+use crate::foundations::{category, Category, Content, Module, Scope, StyleChain};
 use crate::text::TextElem;
 
 /// Typst has special [syntax]($syntax/#math) and library functions to typeset
@@ -135,6 +139,18 @@ use crate::text::TextElem;
 ///   x &= 21 - y/3 & "divide by 3" $
 /// ```
 ///
+/// # Colors
+/// Math respects the text styles in effect, so parts of a formula can be
+/// highlighted by coloring them with the [`text`] function. To color a whole
+/// equation, use a [show-set rule]($styling/#show-rules) instead.
+///
+/// ```example
+/// $ #text(red)[x] + y = #text(blue)[z] $
+///
+/// #show math.equation: set text(fill: eastern)
+/// $ a^2 + b^2 = c^2 $
+/// ```
+///
 /// # Math fonts
 /// You can set the math font by with a [show-set rule]($styling/#show-rules) as
 /// demonstrated below. Note that only special OpenType math fonts are suitable
@@ -176,6 +192,7 @@ pub fn module() -> Module {
     math.define_elem::<UndershellElem>();
     math.define_elem::<OvershellElem>();
     math.define_elem::<CancelElem>();
+    math.define_elem::<PhantomElem>();
     math.define_elem::<FracElem>();
     math.define_elem::<BinomElem>();
     math.define_elem::<VecElem>();
@@ -187,6 +204,10 @@ pub fn module() -> Module {
     math.define_elem::<PrimesElem>();
     math.define_func::<abs>();
     math.define_func::<norm>();
+    math.define_func::<bra>();
+    math.define_func::<ket>();
+    math.define_func::<braket>();
+    math.define_func::<expval>();
     math.define_func::<round>();
     math.define_func::<sqrt>();
     math.define_func::<upright>();
@@ -218,3 +239,28 @@ pub trait LayoutMath {
     /// Layout the element, producing fragment in the context.
     fn layout_math(&self, ctx: &mut MathContext, styles: StyleChain) -> SourceResult<()>;
 }
+
+/// Writes the plain text of a sub-expression, wrapping it in parentheses if it
+/// would otherwise be ambiguous.
+fn plain_text_group(content: &Content, text: &mut EcoString) {
+    let inner = content.plain_text();
+    if is_atomic_text(&inner) {
+        text.push_str(&inner);
+    } else {
+        text.push('(');
+        text.push_str(&inner);
+        text.push(')');
+    }
+}
+
+/// Whether a sub-expression is unambiguous without parentheses when it is
+/// written inline.
+fn is_atomic(content: &Content) -> bool {
+    is_atomic_text(&content.plain_text())
+}
+
+/// Whether the plain text of a sub-expression is a single character or a
+/// number.
+fn is_atomic_text(text: &str) -> bool {
+    text.chars().count() <= 1 || text.chars().all(|c| c.is_ascii_digit())
+}
@@ -0,0 +1,61 @@
+use crate::diag::SourceResult;
+use crate::foundations::{elem, Content, Packed, StyleChain};
+use crate::layout::{Abs, Frame, Point, Size};
+use crate::math::{FrameFragment, LayoutMath, MathContext};
+
+/// Reserves the space of content without displaying it.
+///
+/// This is useful to align parts of a formula that can't be aligned with
+/// `&`, for example across separate equations. When only the width or only
+/// the height should be reserved, the other dimension can be disabled.
+///
+/// To hide content outside of math, use the [`hide`] function.
+///
+/// # Example
+/// ```example
+/// $ a + b + c \
+///   phantom(a +) b + c $
+///
+/// $ sqrt(phantom(b, horizontal: #false) x) + sqrt(y) $
+/// ```
+#[elem(LayoutMath)]
+pub struct PhantomElem {
+    /// Whether to reserve the width of the content.
+    #[default(true)]
+    pub horizontal: bool,
+
+    /// Whether to reserve the height and depth of the content.
+    #[default(true)]
+    pub vertical: bool,
+
+    /// The content whose space to reserve.
+    #[required]
+    pub body: Content,
+}
+
+impl LayoutMath for Packed<PhantomElem> {
+    #[typst_macros::time(name = "math.phantom", span = self.span())]
+    fn layout_math(&self, ctx: &mut MathContext, styles: StyleChain) -> SourceResult<()> {
+        let fragment = ctx.layout_into_fragment(self.body(), styles)?;
+
+        let width = if self.horizontal(styles) { fragment.width() } else { Abs::zero() };
+        let (ascent, descent) = if self.vertical(styles) {
+            (fragment.ascent(), fragment.descent())
+        } else {
+            (Abs::zero(), Abs::zero())
+        };
+
+        // Keep the body's introspection tags, so that labels and counters
+        // within it still work.
+        let class = fragment.class();
+        let body_ascent = fragment.ascent();
+        let mut body = fragment.into_frame();
+        body.hide();
+
+        let mut frame = Frame::soft(Size::new(width, ascent + descent));
+        frame.set_baseline(ascent);
+        frame.push_frame(Point::with_y(ascent - body_ascent), body);
+        ctx.push(FrameFragment::new(ctx, styles, frame).with_class(class));
+        Ok(())
+    }
+}
@@ -1,9 +1,13 @@
+use ecow::EcoString;
+
 use crate::diag::SourceResult;
-use crate::foundations::{elem, func, Content, NativeElement, Packed, StyleChain};
+use crate::foundations::{
+    elem, func, Content, NativeElement, Packed, PlainText, StyleChain,
+};
 use crate::layout::{Abs, Frame, FrameItem, Point, Size};
 use crate::math::{
-    style_cramped, EquationElem, FrameFragment, GlyphFragment, LayoutMath, MathContext,
-    MathSize, Scaled,
+    plain_text_group, style_cramped, EquationElem, FrameFragment, GlyphFragment,
+    LayoutMath, MathContext, MathSize, Scaled,
 };
 use crate::syntax::Span;
 use crate::text::TextElem;
@@ -29,7 +33,7 @@ pub fn sqrt(
 /// ```example
 /// $ root(3, x) $
 /// ```
-#[elem(LayoutMath)]
+#[elem(LayoutMath, PlainText)]
 pub struct RootElem {
     /// Which root of the radicand to take.
     #[positional]
@@ -40,6 +44,16 @@ pub struct RootElem {
     pub radicand: Content,
 }
 
+impl PlainText for Packed<RootElem> {
+    fn plain_text(&self, text: &mut EcoString) {
+        if let Some(index) = self.index(StyleChain::default()) {
+            plain_text_group(&index, text);
+        }
+        text.push('√');
+        plain_text_group(self.radicand(), text);
+    }
+}
+
 impl LayoutMath for Packed<RootElem> {
     #[typst_macros::time(name = "math.root", span = self.span())]
     fn layout_math(&self, ctx: &mut MathContext, styles: StyleChain) -> SourceResult<()> {
@@ -1,4 +1,5 @@
 use ecow::EcoString;
+use serde::Serialize;
 
 use crate::diag::{bail, HintedStrResult, SourceResult};
 use crate::engine::Engine;
@@ -6,8 +7,8 @@ use crate::foundations::{
     cast, elem, Args, Array, Construct, Content, Datetime, Fields, Smart, StyleChain,
     Styles, Value,
 };
-use crate::introspection::Introspector;
-use crate::layout::Page;
+use crate::introspection::{Introspector, Location, TagKind};
+use crate::layout::{Abs, Frame, FrameItem, Page};
 
 /// The root element of a document and its metadata.
 ///
@@ -133,6 +134,107 @@ impl DocumentInfo {
     }
 }
 
+impl Document {
+    /// Counts the words and characters in the laid-out text of the document,
+    /// split into sections that start at each of the given locations.
+    ///
+    /// Text before the first section is not counted. Characters are counted
+    /// excluding whitespace.
+    pub fn count_text(&self, starts: &[Location]) -> Vec<TextCount> {
+        let mut sections = vec![String::new(); starts.len()];
+        let mut current: Option<usize> = None;
+        for page in &self.pages {
+            if let Some(i) = current {
+                sections[i].push(' ');
+            }
+            let mut last = None;
+            collect_text(
+                &page.frame,
+                Abs::zero(),
+                &mut last,
+                starts,
+                &mut current,
+                &mut sections,
+            );
+        }
+
+        sections
+            .iter()
+            .map(|text| TextCount {
+                words: text.split_whitespace().count(),
+                characters: text.chars().filter(|c| !c.is_whitespace()).count(),
+            })
+            .collect()
+    }
+}
+
+/// The number of words and characters in a section of a document.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Serialize)]
+pub struct TextCount {
+    /// The number of whitespace-separated words.
+    pub words: usize,
+    /// The number of characters, excluding whitespace.
+    pub characters: usize,
+}
+
+/// The previously collected text run.
+struct LastRun {
+    /// The vertical position of the run's baseline.
+    y: Abs,
+    /// The font size of the run.
+    size: Abs,
+    /// Whether the run ends with a hyphen inserted by hyphenation.
+    hyphenated: bool,
+}
+
+/// Collect the text in a frame into the section it belongs to.
+fn collect_text(
+    frame: &Frame,
+    offset: Abs,
+    last: &mut Option<LastRun>,
+    starts: &[Location],
+    current: &mut Option<usize>,
+    sections: &mut [String],
+) {
+    for (pos, item) in frame.items() {
+        let y = offset + pos.y;
+        match item {
+            FrameItem::Group(group) => {
+                collect_text(&group.frame, y, last, starts, current, sections);
+            }
+            FrameItem::Tag(tag) if tag.kind() == TagKind::Start => {
+                if let Some(i) = starts.iter().position(|&loc| loc == tag.location()) {
+                    *current = Some(i);
+                }
+            }
+            FrameItem::Text(text) => {
+                let Some(i) = *current else { continue };
+                let section = &mut sections[i];
+
+                // Text runs on different lines are separated by a space
+                // since the trailing space of a line is not laid out. Runs
+                // that are shifted by less than a line, like sub- and
+                // superscripts, belong to the same line. A word that was
+                // hyphenated at the end of the previous line continues
+                // without a space.
+                if let Some(last) = last {
+                    let new_line = (y - last.y).abs() >= last.size.max(text.size);
+                    if new_line && !last.hyphenated {
+                        section.push(' ');
+                    }
+                }
+
+                // A hyphen inserted by hyphenation has no counterpart in the
+                // text and thus an empty range.
+                let hyphenated = text.glyphs.last().is_some_and(|g| g.range.is_empty());
+                *last = Some(LastRun { y, size: text.size, hyphenated });
+                section.push_str(&text.text);
+            }
+            _ => {}
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
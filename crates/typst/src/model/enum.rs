@@ -132,6 +132,21 @@ pub struct EnumElem {
     #[default(1)]
     pub start: usize,
 
+    /// Whether to count downwards instead of upwards.
+    ///
+    /// A reversed enumeration uses the same numbers as a regular one, but in
+    /// the opposite order: It counts down from the number of items and ends
+    /// with the [`start`]($enum.start) number.
+    ///
+    /// ```example
+    /// #set enum(reversed: true)
+    /// + Three
+    /// + Two
+    /// + One
+    /// ```
+    #[default(false)]
+    pub reversed: bool,
+
     /// Whether to display the full numbering, including the numbers of
     /// all parent enumerations.
     ///
@@ -255,7 +270,13 @@ fn layout_enum(
 
     let mut cells = vec![];
     let mut locator = locator.split();
-    let mut number = elem.start(styles);
+    let reversed = elem.reversed(styles);
+    let start = elem.start(styles);
+    let mut number = if reversed {
+        start.saturating_add(elem.children.len().saturating_sub(1))
+    } else {
+        start
+    };
     let mut parents = EnumElem::parents_in(styles);
 
     let full = elem.full(styles);
@@ -296,7 +317,8 @@ fn layout_enum(
             item.body.clone().styled(EnumElem::set_parents(smallvec![number])),
             locator.next(&item.body.span()),
         ));
-        number = number.saturating_add(1);
+        number =
+            if reversed { number.saturating_sub(1) } else { number.saturating_add(1) };
     }
 
     let grid = CellGrid::new(
@@ -1,10 +1,13 @@
 use std::num::NonZeroUsize;
+use std::sync::Arc;
 
-use crate::diag::SourceResult;
+use comemo::Tracked;
+
+use crate::diag::{At, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    elem, Content, NativeElement, Packed, Resolve, Show, ShowSet, Smart, StyleChain,
-    Styles, Synthesize,
+    elem, func, scope, Content, Context, NativeElement, Packed, Resolve, Selector, Show,
+    ShowSet, Smart, StyleChain, Styles, Synthesize,
 };
 use crate::introspection::{
     Count, Counter, CounterUpdate, Locatable, Locator, LocatorLink,
@@ -13,6 +16,7 @@ use crate::layout::{
     layout_frame, Abs, Axes, BlockBody, BlockElem, Em, HElem, Length, Region,
 };
 use crate::model::{Numbering, Outlinable, ParElem, Refable, Supplement};
+use crate::syntax::Span;
 use crate::text::{FontWeight, LocalName, SpaceElem, TextElem, TextSize};
 use crate::utils::NonZeroExt;
 
@@ -48,7 +52,9 @@ use crate::utils::NonZeroExt;
 /// one or multiple equals signs, followed by a space. The number of equals
 /// signs determines the heading's logical nesting depth. The `{offset}` field
 /// can be set to configure the starting depth.
-#[elem(Locatable, Synthesize, Count, Show, ShowSet, LocalName, Refable, Outlinable)]
+#[elem(
+    scope, Locatable, Synthesize, Count, Show, ShowSet, LocalName, Refable, Outlinable
+)]
 pub struct HeadingElem {
     /// The absolute nesting depth of the heading, starting from one. If set
     /// to `{auto}`, it is computed from `{offset + depth}`.
@@ -184,6 +190,86 @@ pub struct HeadingElem {
     pub body: Content,
 }
 
+#[scope]
+impl HeadingElem {
+    /// Retrieves the heading of the given level that is current at the
+    /// location this is called at.
+    ///
+    /// If a heading of the level starts on the current page, the first such
+    /// heading is returned. Otherwise, the last heading of the level before
+    /// the current location is returned. A heading with a smaller level in
+    /// between ends the section of the previous one, so that `{none}` is
+    /// returned in that case.
+    ///
+    /// This is primarily useful for running headers that show the current
+    /// chapter or section:
+    ///
+    /// ```example
+    /// >>> #set page(width: 120pt, height: 100pt)
+    /// #set page(header: context {
+    ///   let chapter = heading.current(level: 1)
+    ///   if chapter != none {
+    ///     emph(chapter.body)
+    ///   }
+    /// })
+    ///
+    /// = Intro
+    /// #lorem(10)
+    ///
+    /// = Details
+    /// #lorem(20)
+    /// ```
+    #[func(contextual)]
+    pub fn current(
+        /// The engine.
+        engine: &mut Engine,
+        /// The callsite context.
+        context: Tracked<Context>,
+        /// The callsite span.
+        span: Span,
+        /// The level of the heading to retrieve.
+        #[named]
+        #[default(NonZeroUsize::ONE)]
+        level: NonZeroUsize,
+    ) -> SourceResult<Option<Content>> {
+        let here = context.location().at(span)?;
+        let level_of = |elem: &Content| {
+            elem.to_packed::<HeadingElem>()
+                .map(|heading| heading.resolve_level(StyleChain::default()))
+        };
+
+        // Prefer a heading that starts on the current page.
+        let selector = Selector::Elem(HeadingElem::elem(), None);
+        let page = engine.introspector.page(here);
+        if let Some(heading) =
+            engine.introspector.query(&selector).into_iter().find(|elem| {
+                level_of(elem) == Some(level)
+                    && elem
+                        .location()
+                        .is_some_and(|loc| engine.introspector.page(loc) == page)
+            })
+        {
+            return Ok(Some(heading));
+        }
+
+        let before = Selector::Before {
+            selector: Arc::new(selector),
+            end: Arc::new(Selector::Location(here)),
+            inclusive: true,
+        };
+
+        for elem in engine.introspector.query(&before).iter().rev() {
+            match level_of(elem) {
+                Some(l) if l == level => return Ok(Some(elem.clone())),
+                Some(l) if l < level => break,
+                _ => {}
+            }
+        }
+
+        Ok(None)
+    }
+}
+
 impl HeadingElem {
     pub fn resolve_level(&self, styles: StyleChain) -> NonZeroUsize {
         self.level(styles).unwrap_or_else(|| {
@@ -32,6 +32,24 @@ use crate::text::{Hyphenate, TextElem};
 /// # Syntax
 /// This function also has dedicated syntax: Text that starts with `http://` or
 /// `https://` is automatically turned into a link.
+///
+/// # Linking text from other sources
+/// The syntax only applies to markup. URLs in strings, for instance from
+/// loaded data, and other identifiers like DOIs are not linked by default.
+/// Since links are regular content, a [show rule]($styling/#show-rules) with a
+/// regular expression can take care of them:
+///
+/// ```example
+/// #show regex("https?://[^\\s]+[^\\s.,;:]"): it => link(it.text)
+/// #show regex("10\\.\\d{4,9}/[^\\s]+[^\\s.,;:]"): it => {
+///   link("https://doi.org/" + it.text)
+/// }
+/// #show link: set text(blue)
+///
+/// #let data = "Source: https://typst.app."
+/// #data \
+/// DOI: 10.1000/182
+/// ```
 #[elem(Show)]
 pub struct LinkElem {
     /// The destination the link points to.
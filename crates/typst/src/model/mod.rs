@@ -20,6 +20,7 @@ mod reference;
 mod strong;
 mod table;
 mod terms;
+mod verse;
 
 pub use self::bibliography::*;
 pub use self::cite::*;
@@ -39,6 +40,7 @@ pub use self::reference::*;
 pub use self::strong::*;
 pub use self::table::*;
 pub use self::terms::*;
+pub use self::verse::*;
 
 use crate::foundations::{category, Category, Scope};
 
@@ -61,6 +63,7 @@ pub fn define(global: &mut Scope) {
     global.define_elem::<FigureElem>();
     global.define_elem::<FootnoteElem>();
     global.define_elem::<QuoteElem>();
+    global.define_elem::<VerseElem>();
     global.define_elem::<CiteElem>();
     global.define_elem::<BibliographyElem>();
     global.define_elem::<EnumElem>();
@@ -68,6 +68,29 @@ use crate::text::TextElem;
 /// be `{none}` even if it exists if Typst hasn't discovered it yet, so you
 /// always need to handle that case in your code.
 ///
+/// Once the element is known, the `supplement` field holds the resolved
+/// supplement (e.g. `[Figure]`), which is useful for restyling all references
+/// of a kind at once:
+///
+/// ```example
+/// #show ref: it => {
+///   let el = it.element
+///   if it.supplement == [Figure] {
+///     let nums = el.counter.at(el.location())
+///     [Fig.~#numbering(el.numbering, ..nums)]
+///   } else {
+///     it
+///   }
+/// }
+///
+/// #figure(
+///   rect(),
+///   caption: [A box],
+/// ) <box>
+///
+/// In @box, we see a box.
+/// ```
+///
 /// ```example
 /// #set heading(numbering: "1.")
 /// #set math.equation(numbering: "(1)")
@@ -151,6 +174,20 @@ impl Synthesize for Packed<RefElem> {
         let target = *elem.target();
         if !BibliographyElem::has(engine, target) {
             if let Ok(found) = engine.introspector.query_label(target).cloned() {
+                // Resolve the supplement so that show rules can access it.
+                if let Some(refable) = found.with::<dyn Refable>() {
+                    let supplement = match elem.supplement(styles).clone() {
+                        Smart::Auto => Some(refable.supplement()),
+                        Smart::Custom(None) => None,
+                        Smart::Custom(Some(supplement)) => {
+                            Some(supplement.resolve(engine, styles, [found.clone()])?)
+                        }
+                    };
+                    elem.push_supplement(Smart::Custom(
+                        supplement.map(Supplement::Content),
+                    ));
+                }
+
                 elem.push_element(Some(found));
                 return Ok(());
             }
@@ -196,29 +233,41 @@ impl Show for Packed<RefElem> {
             })
             .at(span)?;
 
-        let numbering = refable
-            .numbering()
-            .ok_or_else(|| {
-                eco_format!("cannot reference {} without numbering", elem.func().name())
-            })
-            .hint(eco_format!(
-                "you can enable {} numbering with `#set {}(numbering: \"1.\")`",
-                elem.func().name(),
-                if elem.func() == EquationElem::elem() {
-                    "math.equation"
-                } else {
-                    elem.func().name()
-                }
-            ))
-            .at(span)?;
-
         let loc = elem.location().unwrap();
-        let numbers = refable.counter().display_at_loc(
-            engine,
-            loc,
-            styles,
-            &numbering.clone().trimmed(),
-        )?;
+        let tag = elem
+            .to_packed::<EquationElem>()
+            .and_then(|equation| equation.tag(StyleChain::default()));
+
+        // Tagged equations are referred to by their tag instead of a number.
+        let numbers = if let Some(tag) = tag {
+            tag
+        } else {
+            let numbering = refable
+                .numbering()
+                .ok_or_else(|| {
+                    eco_format!(
+                        "cannot reference {} without numbering",
+                        elem.func().name()
+                    )
+                })
+                .hint(eco_format!(
+                    "you can enable {} numbering with `#set {}(numbering: \"1.\")`",
+                    elem.func().name(),
+                    if elem.func() == EquationElem::elem() {
+                        "math.equation"
+                    } else {
+                        elem.func().name()
+                    }
+                ))
+                .at(span)?;
+
+            refable.counter().display_at_loc(
+                engine,
+                loc,
+                styles,
+                &numbering.clone().trimmed(),
+            )?
+        };
 
         let supplement = match self.supplement(styles).as_ref() {
             Smart::Auto => refable.supplement(),
@@ -121,6 +121,22 @@ use crate::visualize::{Paint, Stroke};
 ///   [Robert], b, a, b,
 /// )
 /// ```
+///
+/// # Tables from data
+/// Data loaded with functions like [`csv`] or [`json`] can be turned into a
+/// table by spreading the rows into it. The first row can become the table's
+/// header and each column can be formatted by mapping over the cells:
+///
+/// ```example
+/// #let (header, ..rows) = csv("example.csv")
+/// #let fmt = (upper, emph)
+///
+/// #table(
+///   columns: header.len(),
+///   table.header(..header.map(strong)),
+///   ..rows.map(row => row.zip(fmt).map(((cell, f)) => f(cell))).flatten(),
+/// )
+/// ```
 #[elem(scope, Show, LocalName, Figurable)]
 pub struct TableElem {
     /// The column sizes. See the [grid documentation]($grid) for more
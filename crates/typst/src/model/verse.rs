@@ -0,0 +1,184 @@
+use ecow::EcoString;
+
+use crate::diag::SourceResult;
+use crate::engine::Engine;
+use crate::foundations::{
+    elem, Content, NativeElement, Packed, Show, StyleChain, Styles,
+};
+use crate::layout::{BlockBody, BlockElem, Em, Length};
+use crate::model::{ParElem, ParbreakElem};
+use crate::text::{LinebreakElem, SpaceElem, TextElem};
+
+/// Sets text line by line, like a poem or a postal address.
+///
+/// Line breaks and the leading whitespace of each line are kept as they are
+/// in the text, while it is still set in the regular font. Lines separated by
+/// one or more empty lines form stanzas, which are spaced like paragraphs.
+/// Each line is its own paragraph, so a line that is too long wraps with a
+/// [hanging indent]($verse.hanging-indent).
+///
+/// In markup, a single newline is just a space. To keep the line structure of
+/// your text as it is, pass it as a string or [raw block]($raw) text.
+/// Alternatively, end lines with a [line break]($linebreak) and separate
+/// stanzas with an empty line. Other content, like emphasis, is kept within
+/// its line.
+///
+/// # Example
+/// ```example
+/// #verse("Tyger Tyger, burning bright,
+/// In the forests of the night;
+///   What immortal hand or eye,
+///   Could frame thy fearful symmetry?")
+///
+/// #verse[
+///   _In what distant deeps or skies_ \
+///   Burnt the fire of thine eyes?
+/// ]
+/// ```
+#[elem(Show)]
+pub struct VerseElem {
+    /// The indent of wrapped lines.
+    ///
+    /// ```example
+    /// #set page(width: 140pt)
+    /// #verse(
+    ///   hanging-indent: 2em,
+    ///   "A line that is too long to fit onto the page\nA short line",
+    /// )
+    /// ```
+    #[default(Em::new(1.0).into())]
+    pub hanging_indent: Length,
+
+    /// The number of spaces a tab in the leading whitespace of a line is
+    /// worth.
+    ///
+    /// ```example
+    /// #verse(tab-size: 2, "No indent\n\tTwo spaces")
+    /// ```
+    #[default(4)]
+    pub tab_size: usize,
+
+    /// The text to set.
+    #[required]
+    pub body: Content,
+}
+
+impl Show for Packed<VerseElem> {
+    #[typst_macros::time(name = "verse", span = self.span())]
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let mut builder = Builder::new(self.tab_size(styles));
+        self.body()
+            .sequence_recursive_for_each(&mut |child| builder.push(child));
+        let stanzas = builder.finish();
+
+        // Lines of a stanza are spaced like lines of a paragraph.
+        let mut map = Styles::new();
+        map.set(ParElem::set_spacing(ParElem::leading_in(styles).into()));
+        map.set(ParElem::set_first_line_indent(Length::zero()));
+        map.set(ParElem::set_hanging_indent(self.hanging_indent(styles)));
+        map.set(ParElem::set_justify(false));
+
+        Ok(Content::sequence(stanzas.into_iter().map(|lines| {
+            BlockElem::new()
+                .with_body(Some(BlockBody::Content(
+                    Content::sequence(lines).styled_with_map(map.clone()),
+                )))
+                .pack()
+                .spanned(self.span())
+        })))
+    }
+}
+
+/// Splits the children of a verse into lines and stanzas.
+struct Builder {
+    tab_size: usize,
+    stanzas: Vec<Vec<Content>>,
+    lines: Vec<Content>,
+    line: Vec<Content>,
+}
+
+impl Builder {
+    fn new(tab_size: usize) -> Self {
+        Self {
+            tab_size,
+            stanzas: vec![],
+            lines: vec![],
+            line: vec![],
+        }
+    }
+
+    fn push(&mut self, child: &Content) {
+        if let Some(elem) = child.to_packed::<TextElem>() {
+            for (i, part) in elem.text().split('\n').enumerate() {
+                if i > 0 {
+                    self.end_line();
+                }
+                self.push_text(part, child);
+            }
+        } else if child.is::<SpaceElem>() {
+            if !self.line.is_empty() {
+                self.line.push(child.clone());
+            }
+        } else if child.is::<LinebreakElem>() {
+            self.end_line();
+        } else if child.is::<ParbreakElem>() {
+            self.end_line();
+            self.end_stanza();
+        } else {
+            self.line.push(child.clone());
+        }
+    }
+
+    /// Push a piece of text without newlines, keeping the leading whitespace
+    /// of a line with non-breaking spaces, which don't collapse.
+    fn push_text(&mut self, text: &str, original: &Content) {
+        let text = text.trim_end_matches('\r');
+        if !self.line.is_empty() {
+            if !text.is_empty() {
+                self.line.push(TextElem::packed(text).spanned(original.span()));
+            }
+            return;
+        }
+
+        let trimmed = text.trim_start();
+        if trimmed.is_empty() {
+            return;
+        }
+
+        let mut indent = EcoString::new();
+        for c in text[..text.len() - trimmed.len()].chars() {
+            let width = if c == '\t' { self.tab_size } else { 1 };
+            for _ in 0..width {
+                indent.push('\u{a0}');
+            }
+        }
+
+        indent.push_str(trimmed);
+        self.line.push(TextElem::packed(indent).spanned(original.span()));
+    }
+
+    /// End the current line. An empty line ends the stanza.
+    fn end_line(&mut self) {
+        if self.line.is_empty() {
+            self.end_stanza();
+            return;
+        }
+
+        if !self.lines.is_empty() {
+            self.lines.push(ParbreakElem::shared().clone());
+        }
+        self.lines.push(Content::sequence(std::mem::take(&mut self.line)));
+    }
+
+    fn end_stanza(&mut self) {
+        if !self.lines.is_empty() {
+            self.stanzas.push(std::mem::take(&mut self.lines));
+        }
+    }
+
+    fn finish(mut self) -> Vec<Vec<Content>> {
+        self.end_line();
+        self.end_stanza();
+        self.stanzas
+    }
+}
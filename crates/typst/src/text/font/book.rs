@@ -10,6 +10,10 @@ use super::exceptions::find_exception;
 use crate::text::{Font, FontStretch, FontStyle, FontVariant, FontWeight};
 
 /// Metadata about a collection of fonts.
+///
+/// The book only holds metadata that can be collected without keeping fonts
+/// loaded. Metrics like the x-height are available through [`Font::metrics`]
+/// once a font is loaded.
 #[derive(Debug, Default, Clone, Hash)]
 pub struct FontBook {
     /// Maps from lowercased family names to font indices.
@@ -70,6 +74,21 @@ impl FontBook {
         })
     }
 
+    /// The variants of all fonts in the given `family`.
+    ///
+    /// The `family` should be all lowercase.
+    pub fn variants(&self, family: &str) -> impl Iterator<Item = FontVariant> + '_ {
+        self.select_family(family).map(|id| self.infos[id].variant)
+    }
+
+    /// Whether some font in the given `family` covers all characters of the
+    /// `text`. Whitespace is ignored.
+    ///
+    /// The `family` should be all lowercase.
+    pub fn covers(&self, family: &str, text: &str) -> bool {
+        self.select_family(family).any(|id| self.infos[id].covers(text))
+    }
+
     /// Try to find a font from the given `family` that matches the given
     /// `variant` as closely as possible.
     ///
@@ -210,6 +229,14 @@ impl FontInfo {
         (0..count).filter_map(move |index| Self::new(data, index))
     }
 
+    /// Whether the font covers all characters of the `text`. Whitespace is
+    /// ignored.
+    pub fn covers(&self, text: &str) -> bool {
+        text.chars()
+            .filter(|c| !c.is_whitespace())
+            .all(|c| self.coverage.contains(c as u32))
+    }
+
     /// Compute metadata for a single ttf-parser face.
     pub(super) fn from_ttf(ttf: &ttf_parser::Face) -> Option<Self> {
         let ps_name = find_name(ttf, name_id::POST_SCRIPT_NAME);
@@ -506,6 +533,23 @@ mod tests {
         assert_eq!(typographic_family("Font Ultra Bold"), "Font");
     }
 
+    #[test]
+    fn test_covers() {
+        let info = FontInfo {
+            family: "Test".into(),
+            variant: FontVariant::default(),
+            flags: FontFlags::empty(),
+            coverage: Coverage::from_vec(('a'..='z').map(|c| c as u32).collect()),
+        };
+        let book = FontBook::from_infos([info.clone()]);
+        assert!(info.covers("hello world"));
+        assert!(!info.covers("Hello"));
+        assert!(book.covers("test", "abc"));
+        assert!(!book.covers("test", "über"));
+        assert!(!book.covers("other", "abc"));
+        assert_eq!(book.variants("test").count(), 1);
+    }
+
     #[test]
     fn test_coverage() {
         #[track_caller]
@@ -14,7 +14,7 @@ macro_rules! translation {
     };
 }
 
-const TRANSLATIONS: [(&str, &str); 36] = [
+const TRANSLATIONS: [(&str, &str); 37] = [
     translation!("ar"),
     translation!("ca"),
     translation!("cs"),
@@ -24,6 +24,7 @@ const TRANSLATIONS: [(&str, &str); 36] = [
     translation!("es"),
     translation!("et"),
     translation!("fi"),
+    translation!("fr-CA"),
     translation!("fr"),
     translation!("gl"),
     translation!("gr"),
@@ -313,4 +314,16 @@ mod tests {
         assert!(option_eq(region, "US"));
         assert!(!option_eq(region, "AB"));
     }
+
+    #[test]
+    fn test_translations_are_well_formed() {
+        for (name, _) in TRANSLATIONS {
+            let (lang, region) = match name.split_once('-') {
+                Some((lang, region)) => (lang, Some(region.parse().unwrap())),
+                None => (name, None),
+            };
+            let lang = lang.parse().unwrap();
+            assert!(parse_language_bundle(lang, region).is_ok(), "{name}");
+        }
+    }
 }
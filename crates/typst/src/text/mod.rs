@@ -8,6 +8,7 @@ mod lang;
 mod linebreak;
 #[path = "lorem.rs"]
 mod lorem_;
+mod number;
 mod raw;
 mod shift;
 #[path = "smallcaps.rs"]
@@ -88,7 +89,7 @@ pub(super) fn define(global: &mut Scope) {
 ///   With a function call.
 /// ])
 /// ```
-#[elem(Debug, Construct, PlainText, Repr)]
+#[elem(scope, Debug, Construct, PlainText, Repr)]
 pub struct TextElem {
     /// A font family name or priority list of font family names.
     ///
@@ -0,0 +1,259 @@
+use comemo::Tracked;
+use ecow::{eco_format, EcoString};
+
+use crate::diag::{bail, At, SourceResult};
+use crate::foundations::calc::DecNum;
+use crate::foundations::{func, repr, scope, Context, Smart, Str, StyleChain};
+use crate::syntax::Span;
+use crate::text::{Region, TextElem};
+
+#[scope]
+impl TextElem {
+    /// Formats a number according to the conventions of the current language.
+    ///
+    /// The [language]($text.lang) and [region]($text.region) of the text
+    /// determine which characters are used to separate the thousands and to
+    /// mark the decimal point. Since these depend on the surrounding styles,
+    /// this function can only be used when [context] is available.
+    ///
+    /// ```example
+    /// #context text.num(1234567.891)
+    ///
+    /// #set text(lang: "de")
+    /// #context text.num(1234567.891)
+    ///
+    /// #set text(lang: "fr")
+    /// #context text.num(decimal("1234567.891"), digits: 2)
+    /// ```
+    #[func(contextual, title = "Format Number")]
+    pub fn num(
+        /// The callsite context.
+        context: Tracked<Context>,
+        /// The callsite span.
+        span: Span,
+        /// The number to format.
+        value: DecNum,
+        /// How many digits to show after the decimal point.
+        ///
+        /// The number is rounded if necessary. If `{auto}`, as many digits as
+        /// needed to represent the number are shown.
+        #[named]
+        #[default]
+        digits: Smart<usize>,
+    ) -> SourceResult<Str> {
+        let styles = context.styles().at(span)?;
+        let format = NumberFormat::new(styles);
+        Ok(format.apply(value, digits.custom()).into())
+    }
+
+    /// Formats an amount of money according to the conventions of the current
+    /// language.
+    ///
+    /// Like [`text.num`]($text.num), this uses the separators of the current
+    /// [language]($text.lang) and [region]($text.region). Additionally, the
+    /// amount is rounded to the currency's minor unit and the currency symbol
+    /// is placed where it is customary for the language.
+    ///
+    /// ```example
+    /// #set text(lang: "en", region: "us")
+    /// #context text.money(1499.5)
+    ///
+    /// #set text(lang: "de", region: "de")
+    /// #context text.money(1499.5)
+    /// #context text.money(decimal("1499.5"), currency: "CHF")
+    /// ```
+    #[func(contextual, title = "Format Money")]
+    pub fn money(
+        /// The callsite context.
+        context: Tracked<Context>,
+        /// The callsite span.
+        span: Span,
+        /// The amount to format.
+        amount: DecNum,
+        /// The [ISO 4217](https://en.wikipedia.org/wiki/ISO_4217) code of the
+        /// currency, like `{"EUR"}` or `{"USD"}`.
+        ///
+        /// If `{auto}`, the currency is determined from the
+        /// [region]($text.region) of the text.
+        #[named]
+        #[default]
+        currency: Smart<Str>,
+    ) -> SourceResult<Str> {
+        let styles = context.styles().at(span)?;
+        let code: EcoString = match currency {
+            Smart::Custom(code) => code.as_str().to_ascii_uppercase().into(),
+            Smart::Auto => match TextElem::region_in(styles).and_then(region_currency) {
+                Some(code) => code.into(),
+                None => bail!(
+                    span, "cannot determine currency from the text region";
+                    hint: "specify the currency explicitly, e.g. `currency: \"EUR\"`"
+                ),
+            },
+        };
+
+        if code.len() != 3 || !code.bytes().all(|b| b.is_ascii_alphabetic()) {
+            bail!(span, "expected three-letter currency code");
+        }
+
+        let format = NumberFormat::new(styles);
+        let number = format.apply(amount, Some(minor_units(&code)));
+        let symbol: &str = currency_symbol(&code).unwrap_or(code.as_str());
+        let spaced = symbol.chars().any(char::is_alphabetic);
+
+        let formatted = if format.symbol_first {
+            if spaced {
+                eco_format!("{symbol}\u{a0}{number}")
+            } else if let Some(rest) = number.strip_prefix(repr::MINUS_SIGN) {
+                eco_format!("{}{symbol}{rest}", repr::MINUS_SIGN)
+            } else {
+                eco_format!("{symbol}{number}")
+            }
+        } else {
+            eco_format!("{number}\u{a0}{symbol}")
+        };
+
+        Ok(formatted.into())
+    }
+}
+
+/// The locale-dependent parts of how a number is written.
+struct NumberFormat {
+    /// The character(s) between the integer and fractional part.
+    decimal: &'static str,
+    /// The character(s) between groups of three integer digits.
+    group: &'static str,
+    /// The minimum number of integer digits for grouping to kick in.
+    min_grouping: usize,
+    /// Whether currency symbols precede the amount.
+    symbol_first: bool,
+}
+
+impl NumberFormat {
+    /// Determine the number format for the given styles.
+    fn new(styles: StyleChain) -> Self {
+        let lang = TextElem::lang_in(styles);
+        let region = TextElem::region_in(styles);
+
+        let (decimal, group) = match lang.as_str() {
+            "de" | "it" if region.is_some_and(|r| r.as_str() == "CH") => (".", "’"),
+            "en" | "ja" | "zh" | "he" | "tl" | "ar" => (".", ","),
+            "de" | "nl" | "it" | "es" | "pt" | "da" | "tr" | "ro" | "sl" | "gr"
+            | "ca" | "gl" | "is" | "sr" | "vi" => (",", "."),
+            "fr" => (",", "\u{202f}"),
+            _ => (",", "\u{a0}"),
+        };
+
+        Self {
+            decimal,
+            group,
+            min_grouping: if matches!(lang.as_str(), "es" | "pl") { 5 } else { 4 },
+            symbol_first: matches!(lang.as_str(), "en" | "ja" | "zh" | "tl"),
+        }
+    }
+
+    /// Format a number with this format.
+    fn apply(&self, value: DecNum, digits: Option<usize>) -> EcoString {
+        let plain = match value {
+            DecNum::Int(n) => {
+                let mut plain = repr::format_int_with_base(n, 10);
+                if let Some(count @ 1..) = digits {
+                    plain.push('.');
+                    plain.push_str(&"0".repeat(count));
+                }
+                plain
+            }
+            DecNum::Float(v) if !v.is_finite() => return repr::display_float(v),
+            DecNum::Float(v) => match digits {
+                Some(digits) => repr::display_float_with_digits(v, digits),
+                None => repr::display_float(v),
+            },
+            DecNum::Decimal(d) => match digits {
+                Some(digits) => d.display_with_digits(digits),
+                None => eco_format!("{d}"),
+            },
+        };
+
+        let (sign, unsigned) = match plain.strip_prefix(repr::MINUS_SIGN) {
+            Some(rest) => (repr::MINUS_SIGN, rest),
+            None => ("", plain.as_str()),
+        };
+
+        let (int, frac) = match unsigned.split_once('.') {
+            Some((int, frac)) => (int, Some(frac)),
+            None => (unsigned, None),
+        };
+
+        let mut buf = EcoString::from(sign);
+        for (i, c) in int.chars().enumerate() {
+            let remaining = int.len() - i;
+            if i > 0 && remaining % 3 == 0 && int.len() >= self.min_grouping {
+                buf.push_str(self.group);
+            }
+            buf.push(c);
+        }
+
+        if let Some(frac) = frac {
+            buf.push_str(self.decimal);
+            buf.push_str(frac);
+        }
+
+        buf
+    }
+}
+
+/// The currency that is customarily used in a region.
+fn region_currency(region: Region) -> Option<&'static str> {
+    Some(match region.as_str() {
+        "AT" | "BE" | "CY" | "DE" | "EE" | "ES" | "FI" | "FR" | "GR" | "HR" | "IE"
+        | "IT" | "LT" | "LU" | "LV" | "MT" | "NL" | "PT" | "SI" | "SK" => "EUR",
+        "US" => "USD",
+        "GB" => "GBP",
+        "CH" => "CHF",
+        "JP" => "JPY",
+        "CN" => "CNY",
+        "TW" => "TWD",
+        "IN" => "INR",
+        "KR" => "KRW",
+        "CA" => "CAD",
+        "AU" => "AUD",
+        "BR" => "BRL",
+        "CZ" => "CZK",
+        "DK" => "DKK",
+        "NO" => "NOK",
+        "SE" => "SEK",
+        "PL" => "PLN",
+        "HU" => "HUF",
+        "RU" => "RUB",
+        "UA" => "UAH",
+        "TR" => "TRY",
+        _ => return None,
+    })
+}
+
+/// The symbol of a currency, if it has a widely recognized one.
+fn currency_symbol(code: &str) -> Option<&'static str> {
+    Some(match code {
+        "EUR" => "€",
+        "USD" => "$",
+        "GBP" => "£",
+        "JPY" | "CNY" => "¥",
+        "INR" => "₹",
+        "KRW" => "₩",
+        "RUB" => "₽",
+        "UAH" => "₴",
+        "TRY" => "₺",
+        "PLN" => "zł",
+        "CZK" => "Kč",
+        "DKK" | "NOK" | "SEK" => "kr",
+        _ => return None,
+    })
+}
+
+/// The number of digits after the decimal point that amounts in the currency
+/// are written with.
+fn minor_units(code: &str) -> usize {
+    match code {
+        "JPY" | "KRW" | "HUF" => 0,
+        _ => 2,
+    }
+}
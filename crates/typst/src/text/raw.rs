@@ -72,6 +72,21 @@ type ThemeArgType = Smart<Option<EcoString>>;
 /// needed, start the text with a single space (which will be trimmed) or use
 /// the single backtick syntax. If your text should start or end with a
 /// backtick, put a space before or after it (it will be trimmed).
+///
+/// # Ligatures
+/// Standard [ligatures]($text.ligatures) are disabled in raw text so that code
+/// is displayed exactly as it was written. If you use a programming font with
+/// code ligatures and want to see them, you can enable them again:
+///
+/// ```example
+/// #show raw: set text(ligatures: true)
+/// `a != b`
+/// ```
+///
+/// Only standard ligatures are affected. Discretionary and historical
+/// ligatures are off by default anyway, and finer control, for example over
+/// individual OpenType features, is available through the text
+/// [`features`]($text.features) parameter.
 #[elem(
     scope,
     title = "Raw Text / Code",
@@ -465,6 +480,7 @@ impl ShowSet for Packed<RawElem> {
         out.set(TextElem::set_overhang(false));
         out.set(TextElem::set_lang(Lang::ENGLISH));
         out.set(TextElem::set_hyphenate(Hyphenate(Smart::Custom(false))));
+        out.set(TextElem::set_ligatures(false));
         out.set(TextElem::set_size(TextSize(Em::new(0.8).into())));
         out.set(TextElem::set_font(FontList(vec![FontFamily::new("DejaVu Sans Mono")])));
         if self.block(styles) {
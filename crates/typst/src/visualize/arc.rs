@@ -0,0 +1,186 @@
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+use crate::diag::{bail, SourceResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    elem, Cast, Content, NativeElement, Packed, Show, Smart, StyleChain,
+};
+use crate::introspection::Locator;
+use crate::layout::{
+    Abs, Angle, BlockElem, Frame, FrameItem, Length, Point, Region, Size,
+};
+use crate::utils::Numeric;
+use crate::visualize::{FillRule, FixedStroke, Geometry, Paint, Path, Shape, Stroke};
+
+/// A circular arc, chord, or pie slice.
+///
+/// Angles are measured clockwise from the positive x-axis, like the ones of
+/// [`rotate`]. The arc is drawn from the `start` to the `stop` angle. If
+/// `stop` is smaller than `start`, it is drawn counter-clockwise instead.
+///
+/// The arc is laid out in a square of twice its radius with the circle's
+/// center in the middle. This way, multiple arcs with the same radius can
+/// simply be [placed]($place) on top of each other, for instance to build a
+/// pie chart.
+///
+/// # Example
+/// ```example
+/// #arc(radius: 15pt, stop: 135deg)
+/// #arc(radius: 15pt, stop: 135deg, kind: "chord")
+/// #arc(
+///   radius: 15pt,
+///   start: -90deg,
+///   stop: 45deg,
+///   kind: "pie",
+///   fill: blue.lighten(60%),
+///   stroke: blue,
+/// )
+/// ```
+#[elem(Show)]
+pub struct ArcElem {
+    /// The radius of the circle the arc lies on.
+    #[resolve]
+    #[default(Abs::pt(5.0).into())]
+    pub radius: Length,
+
+    /// The angle at which the arc starts.
+    #[default(Angle::zero())]
+    pub start: Angle,
+
+    /// The angle at which the arc stops.
+    ///
+    /// The arc spans at most a full circle, even if the two angles are further
+    /// apart.
+    #[default(Angle::deg(90.0))]
+    pub stop: Angle,
+
+    /// What kind of shape to draw.
+    ///
+    /// ```example
+    /// #set arc(radius: 12pt, stop: 270deg)
+    /// #arc(kind: "open")
+    /// #arc(kind: "chord")
+    /// #arc(kind: "pie")
+    /// ```
+    #[default(ArcKind::Open)]
+    pub kind: ArcKind,
+
+    /// How to fill the shape.
+    ///
+    /// When setting a fill, the default stroke disappears. To create a shape
+    /// with both fill and stroke, you have to configure both. An open arc is
+    /// filled as if its ends were connected by a straight line.
+    pub fill: Option<Paint>,
+
+    /// How to [stroke] the shape.
+    ///
+    /// Can be set to `{none}` to disable the stroke or to `{auto}` for a
+    /// stroke of `{1pt}` black if and if only if no fill is given.
+    #[resolve]
+    #[fold]
+    pub stroke: Smart<Option<Stroke>>,
+}
+
+impl Show for Packed<ArcElem> {
+    fn show(&self, _: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(BlockElem::single_layouter(self.clone(), layout_arc)
+            .pack()
+            .spanned(self.span()))
+    }
+}
+
+/// The kind of shape drawn by an [arc]($arc).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum ArcKind {
+    /// Just the curved part of the circle.
+    Open,
+    /// The curved part closed with a straight line between its ends.
+    Chord,
+    /// The curved part closed with two lines to the circle's center.
+    Pie,
+}
+
+/// Layout the arc.
+#[typst_macros::time(span = elem.span())]
+fn layout_arc(
+    elem: &Packed<ArcElem>,
+    _: &mut Engine,
+    _: Locator,
+    styles: StyleChain,
+    _: Region,
+) -> SourceResult<Frame> {
+    let radius = elem.radius(styles);
+    if !radius.is_finite() {
+        bail!(elem.span(), "cannot create arc with infinite radius");
+    } else if radius < Abs::zero() {
+        bail!(elem.span(), "arc radius must not be negative");
+    }
+
+    let mut frame = Frame::hard(Size::splat(2.0 * radius));
+
+    let start = elem.start(styles).to_rad();
+    let sweep = (elem.stop(styles).to_rad() - start).clamp(-TAU, TAU);
+    if radius == Abs::zero() || sweep == 0.0 {
+        return Ok(frame);
+    }
+
+    // Prepare fill and stroke.
+    let fill = elem.fill(styles);
+    let stroke = match elem.stroke(styles) {
+        Smart::Auto if fill.is_none() => Some(FixedStroke::default()),
+        Smart::Auto => None,
+        Smart::Custom(stroke) => stroke.map(Stroke::unwrap_or_default),
+    };
+
+    let kind = elem.kind(styles);
+    let center = Point::splat(radius);
+    let mut path = Path::new();
+    if kind == ArcKind::Pie {
+        path.move_to(center);
+        path.line_to(arc_point(center, radius, start));
+    } else {
+        path.move_to(arc_point(center, radius, start));
+    }
+
+    arc_to(&mut path, center, radius, start, sweep);
+
+    if kind != ArcKind::Open {
+        path.close_path();
+    }
+
+    let shape = Shape {
+        geometry: Geometry::Path(path),
+        stroke,
+        fill,
+        fill_rule: FillRule::default(),
+    };
+    frame.push(Point::zero(), FrameItem::Shape(shape, elem.span()));
+    Ok(frame)
+}
+
+/// Appends cubic Bézier curves approximating a circular arc to the path.
+///
+/// The path's cursor must already be at the arc's start point.
+fn arc_to(path: &mut Path, center: Point, radius: Abs, start: f64, sweep: f64) {
+    // Split the arc into segments of at most a quarter circle, which keeps
+    // the approximation error negligible.
+    let count = (sweep.abs() / FRAC_PI_2).ceil().max(1.0);
+    let step = sweep / count;
+    let k = 4.0 / 3.0 * (step / 4.0).tan();
+
+    let mut a0 = start;
+    for _ in 0..count as usize {
+        let a1 = a0 + step;
+        let p0 = arc_point(center, radius, a0);
+        let p3 = arc_point(center, radius, a1);
+        let c1 = p0 + Point::new(-radius * (k * a0.sin()), radius * (k * a0.cos()));
+        let c2 = p3 - Point::new(-radius * (k * a1.sin()), radius * (k * a1.cos()));
+        path.cubic_to(c1, c2, p3);
+        a0 = a1;
+    }
+}
+
+/// The point on the circle at the given angle.
+fn arc_point(center: Point, radius: Abs, angle: f64) -> Point {
+    center + Point::new(radius * angle.cos(), radius * angle.sin())
+}
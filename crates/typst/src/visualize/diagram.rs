@@ -0,0 +1,422 @@
+use crate::diag::{bail, HintedStrResult, HintedString, SourceResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    cast, elem, scope, Content, NativeElement, Packed, Resolve, Show, StyleChain,
+};
+use crate::introspection::Locator;
+use crate::layout::{
+    layout_frame, Abs, Alignment, Angle, Axes, BlockElem, Em, Frame, FrameItem, Length,
+    Point, Region, Size,
+};
+use crate::syntax::Span;
+use crate::utils::Numeric;
+use crate::visualize::{FixedStroke, Geometry, Path, Stroke};
+
+/// The distance between a node's content and the start or end of an edge.
+const NODE_PADDING: Em = Em::new(0.3);
+
+/// The distance between an edge and its label.
+const LABEL_PADDING: Em = Em::new(0.2);
+
+/// The distance between the two lines of a double edge.
+const DOUBLE_GAP: Em = Em::new(0.15);
+
+/// The length of an arrow head's strokes.
+const ARROW_LENGTH: Em = Em::new(0.4);
+
+/// Arranges content on a grid and connects it with arrows.
+///
+/// This is enough to typeset basic commutative diagrams. Nodes are placed with
+/// [`diagram.node`]($diagram.node) in the cell at the given column and row.
+/// Columns and rows are as wide and tall as their largest node. Edges are
+/// added with [`diagram.edge`]($diagram.edge) and drawn from the center of one
+/// cell towards the center of another one, stopping short of the nodes.
+///
+/// # Example
+/// ```example
+/// #diagram(
+///   diagram.node((0, 0), $A$),
+///   diagram.node((1, 0), $B$),
+///   diagram.node((0, 1), $C$),
+///   diagram.node((1, 1), $D$),
+///   diagram.edge((0, 0), (1, 0), $f$),
+///   diagram.edge((0, 0), (0, 1), $g$, label-side: right),
+///   diagram.edge((1, 0), (1, 1), $h$),
+///   diagram.edge((0, 1), (1, 1), $k$, label-side: right),
+///   diagram.edge((0, 0), (1, 1), double: true),
+/// )
+/// ```
+#[elem(scope, Show)]
+pub struct DiagramElem {
+    /// The gap between columns.
+    #[resolve]
+    #[default(Em::new(3.0).into())]
+    pub column_gap: Length,
+
+    /// The gap between rows.
+    #[resolve]
+    #[default(Em::new(3.0).into())]
+    pub row_gap: Length,
+
+    /// How to [stroke] the edges.
+    #[resolve]
+    #[fold]
+    pub stroke: Stroke,
+
+    /// The nodes and edges of the diagram.
+    #[variadic]
+    pub children: Vec<DiagramChild>,
+}
+
+#[scope]
+impl DiagramElem {
+    #[elem]
+    type DiagramNode;
+
+    #[elem]
+    type DiagramEdge;
+}
+
+impl Show for Packed<DiagramElem> {
+    fn show(&self, _: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(BlockElem::single_layouter(self.clone(), layout_diagram)
+            .pack()
+            .spanned(self.span()))
+    }
+}
+
+/// Any child of a diagram element.
+#[derive(Debug, PartialEq, Clone, Hash)]
+pub enum DiagramChild {
+    Node(Packed<DiagramNode>),
+    Edge(Packed<DiagramEdge>),
+}
+
+cast! {
+    DiagramChild,
+    self => match self {
+        Self::Node(node) => node.into_value(),
+        Self::Edge(edge) => edge.into_value(),
+    },
+    v: Content => {
+        v.try_into()?
+    },
+}
+
+impl TryFrom<Content> for DiagramChild {
+    type Error = HintedString;
+
+    fn try_from(value: Content) -> HintedStrResult<Self> {
+        let value = match value.into_packed::<DiagramNode>() {
+            Ok(node) => return Ok(Self::Node(node)),
+            Err(value) => value,
+        };
+
+        match value.into_packed::<DiagramEdge>() {
+            Ok(edge) => Ok(Self::Edge(edge)),
+            Err(_) => bail!(
+                "expected `diagram.node` or `diagram.edge`";
+                hint: "wrap other content in `diagram.node` to place it"
+            ),
+        }
+    }
+}
+
+/// A node in a diagram.
+#[elem(name = "node", title = "Diagram Node", Show)]
+pub struct DiagramNode {
+    /// The column and row of the node, starting from zero.
+    #[required]
+    pub pos: Axes<usize>,
+
+    /// The node's content.
+    #[required]
+    pub body: Content,
+}
+
+impl Show for Packed<DiagramNode> {
+    fn show(&self, _: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(self.body().clone())
+    }
+}
+
+/// An arrow between two cells of a diagram.
+#[elem(name = "edge", title = "Diagram Edge", Show)]
+pub struct DiagramEdge {
+    /// The column and row of the cell the edge starts at.
+    #[required]
+    pub from: Axes<usize>,
+
+    /// The column and row of the cell the edge points to.
+    #[required]
+    pub to: Axes<usize>,
+
+    /// A label to place next to the middle of the edge.
+    #[positional]
+    pub body: Option<Content>,
+
+    /// On which side of the edge to place the label, when looking into the
+    /// direction of the edge.
+    ///
+    /// ```example
+    /// #diagram(
+    ///   diagram.node((0, 0), $A$),
+    ///   diagram.node((1, 0), $B$),
+    ///   diagram.edge((0, 0), (1, 0), $f$),
+    ///   diagram.edge((1, 0), (0, 0), $g$, bend: 30deg, label-side: right),
+    /// )
+    /// ```
+    #[default(LabelSide::Left)]
+    pub label_side: LabelSide,
+
+    /// How far the edge bends away from a straight line, as the angle between
+    /// the straight line and the edge at its start. Positive angles bend to
+    /// the left. Must lie strictly between `{-90deg}` and `{90deg}`.
+    #[default(Angle::zero())]
+    pub bend: Angle,
+
+    /// Whether to draw the edge with two parallel lines, as is customary for
+    /// equalities and natural transformations.
+    #[default(false)]
+    pub double: bool,
+
+    /// Whether to draw an arrow head at the end of the edge.
+    #[default(true)]
+    pub head: bool,
+}
+
+impl Show for Packed<DiagramEdge> {
+    fn show(&self, _: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(Content::empty())
+    }
+}
+
+/// The side of an edge a label is placed on.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum LabelSide {
+    /// To the left of the edge.
+    Left,
+    /// To the right of the edge.
+    Right,
+}
+
+cast! {
+    LabelSide,
+    self => match self {
+        Self::Left => Alignment::LEFT.into_value(),
+        Self::Right => Alignment::RIGHT.into_value(),
+    },
+    v: Alignment => match v {
+        Alignment::LEFT => Self::Left,
+        Alignment::RIGHT => Self::Right,
+        _ => bail!("expected either `left` or `right`"),
+    },
+}
+
+/// Layout the diagram.
+#[typst_macros::time(span = elem.span())]
+fn layout_diagram(
+    elem: &Packed<DiagramElem>,
+    engine: &mut Engine,
+    locator: Locator,
+    styles: StyleChain,
+    _: Region,
+) -> SourceResult<Frame> {
+    let mut locator = locator.split();
+    let pod = Region::new(Axes::splat(Abs::inf()), Axes::splat(false));
+
+    // Lay out the nodes and measure the columns and rows.
+    let mut nodes = vec![];
+    let mut widths: Vec<Abs> = vec![];
+    let mut heights: Vec<Abs> = vec![];
+    for child in elem.children() {
+        let DiagramChild::Node(node) = child else { continue };
+        let pos = *node.pos();
+        let frame =
+            layout_frame(engine, node.body(), locator.next(&node.span()), styles, pod)?;
+        if widths.len() <= pos.x {
+            widths.resize(pos.x + 1, Abs::zero());
+        }
+        if heights.len() <= pos.y {
+            heights.resize(pos.y + 1, Abs::zero());
+        }
+        widths[pos.x].set_max(frame.width());
+        heights[pos.y].set_max(frame.height());
+        nodes.push((pos, frame));
+    }
+
+    let column_gap = elem.column_gap(styles);
+    let row_gap = elem.row_gap(styles);
+    let centers = |sizes: &[Abs], gap: Abs, index: usize| {
+        sizes.iter().take(index).map(|&size| size + gap).sum::<Abs>()
+            + sizes.get(index).copied().unwrap_or_default() / 2.0
+    };
+    let center = |pos: Axes<usize>| {
+        Point::new(centers(&widths, column_gap, pos.x), centers(&heights, row_gap, pos.y))
+    };
+    let total = |sizes: &[Abs], gap: Abs| {
+        sizes.iter().sum::<Abs>() + gap * sizes.len().saturating_sub(1) as f64
+    };
+
+    let size = Size::new(total(&widths, column_gap), total(&heights, row_gap));
+    if !size.is_finite() {
+        bail!(elem.span(), "cannot create diagram with infinite size");
+    }
+
+    let mut frame = Frame::soft(size);
+    let mut extents = vec![];
+    for (pos, node) in nodes {
+        let c = center(pos);
+        extents.push((pos, node.size()));
+        frame.push_frame(c - node.size().to_point() / 2.0, node);
+    }
+
+    // The half size of the box an edge must not enter at a cell.
+    let padding = NODE_PADDING.resolve(styles);
+    let half_extent = |pos: Axes<usize>| {
+        let size = extents
+            .iter()
+            .filter(|(p, _)| *p == pos)
+            .fold(Size::zero(), |acc, (_, size)| acc.max(*size));
+        size.to_point() / 2.0 + Point::splat(padding)
+    };
+
+    let stroke = elem.stroke(styles).unwrap_or_default();
+    for child in elem.children() {
+        let DiagramChild::Edge(edge) = child else { continue };
+        let from = *edge.from();
+        let to = *edge.to();
+        if from == to {
+            bail!(edge.span(), "cannot draw an edge from a cell to itself");
+        }
+
+        let span = edge.span();
+        let start = center(from);
+        let end = center(to);
+        let bend = edge.bend(styles);
+        if bend.to_deg().abs() >= 90.0 {
+            bail!(span, "bend must be between -90deg and 90deg");
+        }
+
+        let control = bent_control(start, end, bend.to_rad());
+
+        // Stop short of the nodes, following the edge's tangent at both ends.
+        let start = start + exit_offset(control - start, half_extent(from));
+        let end = end + exit_offset(control - end, half_extent(to));
+
+        if edge.double(styles) {
+            let gap = DOUBLE_GAP.resolve(styles) / 2.0;
+            for side in [-1.0, 1.0] {
+                let shift = normal(end - start) * (gap * side).to_raw();
+                push_curve(
+                    &mut frame,
+                    start + shift,
+                    control + shift,
+                    end + shift,
+                    &stroke,
+                    span,
+                );
+            }
+        } else {
+            push_curve(&mut frame, start, control, end, &stroke, span);
+        }
+
+        if edge.head(styles) {
+            let length = ARROW_LENGTH.resolve(styles);
+            let dir = unit(end - control);
+            let mut path = Path::new();
+            for angle in [-0.5, 0.5] {
+                let (sin, cos) = f64::sin_cos(std::f64::consts::PI + angle);
+                let arm = Point::new(
+                    Abs::raw(dir.x.to_raw() * cos - dir.y.to_raw() * sin),
+                    Abs::raw(dir.x.to_raw() * sin + dir.y.to_raw() * cos),
+                );
+                path.move_to(end);
+                path.line_to(end + arm * length.to_raw());
+            }
+            let shape = Geometry::Path(path).stroked(stroke.clone());
+            frame.push(Point::zero(), FrameItem::Shape(shape, span));
+        }
+
+        if let Some(label) = edge.body(styles) {
+            let label = layout_frame(engine, &label, locator.next(&span), styles, pod)?;
+            let mid = (start + control * 2.0 + end) / 4.0;
+            let mut n = normal(end - start);
+            if edge.label_side(styles) == LabelSide::Right {
+                n = -n;
+            }
+
+            // Move the label's center away from the edge far enough for its
+            // bounding box not to touch the edge.
+            let half = label.size().to_point() / 2.0;
+            let distance = half.x * n.x.to_raw().abs()
+                + half.y * n.y.to_raw().abs()
+                + LABEL_PADDING.resolve(styles);
+            let pos = mid + n * distance.to_raw() - half;
+            frame.push_frame(pos, label);
+        }
+    }
+
+    Ok(frame)
+}
+
+/// Push the curve with the given quadratic control point to the frame.
+///
+/// Paths only support cubic Bézier curves, so the quadratic curve is converted
+/// into the equivalent cubic one.
+fn push_curve(
+    frame: &mut Frame,
+    start: Point,
+    control: Point,
+    end: Point,
+    stroke: &FixedStroke,
+    span: Span,
+) {
+    let mut path = Path::new();
+    path.move_to(start);
+    path.cubic_to(
+        start + (control - start) * (2.0 / 3.0),
+        end + (control - end) * (2.0 / 3.0),
+        end,
+    );
+    let shape = Geometry::Path(path).stroked(stroke.clone());
+    frame.push(Point::zero(), FrameItem::Shape(shape, span));
+}
+
+/// The control point of a quadratic curve from `start` to `end` that leaves
+/// `start` at the given angle relative to the straight line.
+fn bent_control(start: Point, end: Point, bend: f64) -> Point {
+    let mid = (start + end) / 2.0;
+    let half = (end - start).hypot() / 2.0;
+    mid + normal(end - start) * (half * bend.tan()).to_raw()
+}
+
+/// How far to move from the center of a cell in the given direction to leave
+/// the box with the given half extent.
+fn exit_offset(direction: Point, half: Point) -> Point {
+    let dir = unit(direction);
+    let tx = if dir.x.is_zero() { f64::INFINITY } else { half.x / dir.x.abs() };
+    let ty = if dir.y.is_zero() { f64::INFINITY } else { half.y / dir.y.abs() };
+    let t = tx.min(ty);
+    if t.is_finite() {
+        Point::new(Abs::raw(dir.x.to_raw() * t), Abs::raw(dir.y.to_raw() * t))
+    } else {
+        Point::zero()
+    }
+}
+
+/// The vector with unit length pointing into the same direction. The
+/// components are stored as raw values.
+fn unit(v: Point) -> Point {
+    let length = v.hypot().to_raw();
+    if length == 0.0 {
+        return Point::zero();
+    }
+    Point::new(Abs::raw(v.x.to_raw() / length), Abs::raw(v.y.to_raw() / length))
+}
+
+/// The unit normal pointing to the left of the given direction.
+fn normal(v: Point) -> Point {
+    let u = unit(v);
+    Point::new(u.y, -u.x)
+}
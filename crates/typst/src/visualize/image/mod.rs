@@ -21,15 +21,15 @@ use crate::foundations::{
 };
 use crate::introspection::Locator;
 use crate::layout::{
-    Abs, Axes, BlockElem, FixedAlignment, Frame, FrameItem, Length, Point, Region, Rel,
-    Size, Sizing,
+    Abs, Axes, BlockElem, FixedAlignment, Frame, FrameItem, HAlignment, Length, Point,
+    Region, Rel, Size, Sizing, VAlignment,
 };
 use crate::loading::Readable;
 use crate::model::Figurable;
 use crate::syntax::{Span, Spanned};
-use crate::text::{families, LocalName};
-use crate::utils::LazyHash;
-use crate::visualize::Path;
+use crate::text::{families, LocalName, TextElem};
+use crate::utils::{LazyHash, Numeric, Scalar};
+use crate::visualize::{Color, Path, RectElem};
 use crate::World;
 
 /// A raster or vector graphic.
@@ -99,9 +99,21 @@ pub struct ImageElem {
     /// ```
     #[default(ImageFit::Cover)]
     pub fit: ImageFit,
+
+    /// The resolution of the image in dots per inch, which determines its
+    /// natural size if neither `width` nor `height` is set.
+    ///
+    /// When `{auto}`, the resolution stored in the image's metadata is used
+    /// and if there is none, 72 dpi are assumed for raster images.
+    ///
+    /// ```example
+    /// #image("tiger.jpg", dpi: 300)
+    /// ```
+    pub dpi: Smart<Scalar>,
 }
 
 #[scope]
+#[allow(clippy::too_many_arguments)]
 impl ImageElem {
     /// Decode a raster or vector graphic from bytes or a string.
     ///
@@ -136,6 +148,9 @@ impl ImageElem {
         /// How the image should adjust itself to a given area.
         #[named]
         fit: Option<ImageFit>,
+        /// The resolution of the image in dots per inch.
+        #[named]
+        dpi: Option<Smart<Scalar>>,
     ) -> StrResult<Content> {
         let mut elem = ImageElem::new(EcoString::new(), data);
         if let Some(format) = format {
@@ -153,8 +168,46 @@ impl ImageElem {
         if let Some(fit) = fit {
             elem.push_fit(fit);
         }
+        if let Some(dpi) = dpi {
+            elem.push_dpi(dpi);
+        }
         Ok(elem.pack().spanned(span))
     }
+
+    /// Creates a placeholder for an image that does not exist yet.
+    ///
+    /// The placeholder is a gray box of the given size that shows a label in
+    /// its center. This is useful to prototype layouts before the actual
+    /// images are available.
+    ///
+    /// ```example
+    /// #image.placeholder(width: 3cm, height: 2cm)
+    /// #image.placeholder(height: 1cm)[Logo]
+    /// ```
+    #[func(title = "Placeholder Image")]
+    pub fn placeholder(
+        /// The call span of this function.
+        span: Span,
+        /// The width of the placeholder.
+        #[named]
+        #[default(Rel::one())]
+        width: Rel<Length>,
+        /// The height of the placeholder.
+        #[named]
+        #[default(Abs::cm(4.0).into())]
+        height: Rel<Length>,
+        /// The label to show in the placeholder.
+        #[default(TextElem::packed("Image"))]
+        label: Content,
+    ) -> Content {
+        RectElem::new()
+            .with_width(Smart::Custom(width))
+            .with_height(Sizing::Rel(height))
+            .with_fill(Some(Color::SILVER.into()))
+            .with_body(Some(label.aligned(HAlignment::Center + VAlignment::Horizon)))
+            .pack()
+            .spanned(span)
+    }
 }
 
 impl Show for Packed<ImageElem> {
@@ -242,7 +295,11 @@ fn layout_image(
     } else {
         // If neither is forced, take the natural image size at the image's
         // DPI bounded by the available space.
-        let dpi = image.dpi().unwrap_or(Image::DEFAULT_DPI);
+        let dpi = match elem.dpi(styles) {
+            Smart::Custom(dpi) if dpi.is_finite() && dpi.get() > 0.0 => dpi.get(),
+            Smart::Custom(_) => bail!(span, "dpi must be positive"),
+            Smart::Auto => image.dpi().unwrap_or(Image::DEFAULT_DPI),
+        };
         let natural = Axes::new(pxw, pxh).map(|v| Abs::inches(v / dpi));
         Size::new(
             natural.x.min(region.size.x).min(region.size.y * px_ratio),
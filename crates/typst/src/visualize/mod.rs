@@ -1,6 +1,8 @@
 //! Drawing and visualization.
 
+mod arc;
 mod color;
+mod diagram;
 mod gradient;
 mod image;
 mod line;
@@ -11,7 +13,9 @@ mod polygon;
 mod shape;
 mod stroke;
 
+pub use self::arc::*;
 pub use self::color::*;
+pub use self::diagram::*;
 pub use self::gradient::*;
 pub use self::image::*;
 pub use self::line::*;
@@ -45,6 +49,8 @@ pub(super) fn define(global: &mut Scope) {
     global.define_elem::<SquareElem>();
     global.define_elem::<EllipseElem>();
     global.define_elem::<CircleElem>();
+    global.define_elem::<ArcElem>();
     global.define_elem::<PolygonElem>();
     global.define_elem::<PathElem>();
+    global.define_elem::<DiagramElem>();
 }
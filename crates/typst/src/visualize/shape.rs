@@ -27,6 +27,24 @@ use crate::visualize::{FixedStroke, Paint, Path, Stroke};
 ///   to fit the content.
 /// ]
 /// ```
+///
+/// # Shadows
+/// Soft drop shadows are not supported as they cannot be expressed uniformly
+/// across all export formats. A hard shadow can instead be created by placing
+/// an offset copy of the shape behind it:
+///
+/// ```example
+/// #let shadowed(body) = box(
+///   inset: (right: 4pt, bottom: 4pt),
+///   {
+///     let shadow = rect(fill: luma(180), hide(body))
+///     place(dx: 4pt, dy: 4pt, shadow)
+///     rect(fill: white, stroke: black, body)
+///   },
+/// )
+///
+/// #shadowed[Seen from above]
+/// ```
 #[elem(title = "Rectangle", Show)]
 pub struct RectElem {
     /// The rectangle's width, relative to its parent container.
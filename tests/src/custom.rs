@@ -1,7 +1,9 @@
 use std::fmt::Write;
 
-use typst::foundations::Smart;
-use typst::model::{Document, DocumentInfo};
+use typst::diag::Warned;
+use typst::foundations::{NativeElement, Smart};
+use typst::layout::{Abs, Size};
+use typst::model::{Document, DocumentInfo, HeadingElem};
 use typst::World;
 
 use crate::collect::Test;
@@ -35,11 +37,52 @@ pub fn check(test: &Test, world: &TestWorld, doc: Option<&Document>) -> String {
             test_eq!(sink, info.author, ["Changed"]);
             test_eq!(sink, info.title.as_deref(), Some("Alternative"));
         }
+        "document-count-text-sections" => {
+            test_eq!(sink, word_counts(doc), [4, 2]);
+        }
+        "document-count-text-hyphenated" => {
+            test_eq!(sink, word_counts(doc), [5]);
+        }
+        "document-count-text-sub-super" => {
+            test_eq!(sink, word_counts(doc), [4]);
+        }
+        "snippet-size" => {
+            let frame = typst::compile_main_snippet(world).output.unwrap();
+            test_eq!(sink, frame.size(), Size::new(Abs::pt(20.0), Abs::pt(10.0)));
+        }
+        "snippet-introspection" => {
+            let frame = typst::compile_main_snippet(world).output.unwrap();
+            test_eq!(sink, frame.width(), Abs::pt(30.0));
+
+            // With a single pass, the final value is not known yet.
+            let Warned { output, warnings } =
+                typst::compile_main_snippet_with_passes(world, 1);
+            let frame = output.unwrap();
+            test_eq!(sink, frame.width(), Abs::zero());
+            test_eq!(sink, warnings.len(), 1);
+        }
+        "snippet-page-config" => {
+            let errors = typst::compile_main_snippet(world).output.unwrap_err();
+            test_eq!(
+                sink,
+                errors[0].message,
+                "page configuration is not allowed inside of containers"
+            );
+        }
         _ => {}
     }
     sink
 }
 
+/// Count the words in the sections of the document that start at its headings.
+fn word_counts(doc: Option<&Document>) -> Vec<usize> {
+    let Some(doc) = doc else { return vec![] };
+    let headings = doc.introspector.query(&HeadingElem::elem().select());
+    let starts: Vec<_> =
+        headings.iter().filter_map(|heading| heading.location()).collect();
+    doc.count_text(&starts).iter().map(|count| count.words).collect()
+}
+
 /// Extract the document information.
 fn info(doc: Option<&Document>) -> DocumentInfo {
     doc.map(|doc| doc.info.clone()).unwrap_or_default()
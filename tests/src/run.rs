@@ -383,10 +383,12 @@ fn render(document: &Document, pixel_per_pt: f32) -> sk::Pixmap {
 
     let mut y = 0.0;
     for page in &document.pages {
-        let ts =
-            sk::Transform::from_scale(pixel_per_pt, pixel_per_pt).post_translate(0.0, y);
+        let offset = (page.bleed + page.slug).to_pt() as f32;
+        let ts = sk::Transform::from_scale(pixel_per_pt, pixel_per_pt)
+            .post_translate(0.0, y)
+            .pre_translate(offset, offset);
         render_links(&mut pixmap, ts, &page.frame);
-        y += (pixel_per_pt * page.frame.height().to_pt() as f32).round().max(1.0) + gap;
+        y += (pixel_per_pt * page.media_size().y.to_pt() as f32).round().max(1.0) + gap;
     }
 
     pixmap
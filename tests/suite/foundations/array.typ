@@ -356,6 +356,16 @@
 #test((2, 1, 3, -10, -5, 8, 6, -7, 2).sorted(key: x => x), (-10, -7, -5, 1, 2, 2, 3, 6, 8))
 #test((2, 1, 3, -10, -5, 8, 6, -7, 2).sorted(key: x => x * x), (1, 2, 2, 3, -5, 6, -7, 8, -10))
 
+--- array-sorted-locale ---
+#test(("Zebra", "Äpfel", "Apfel").sorted(), ("Apfel", "Zebra", "Äpfel"))
+#test(("Zebra", "Äpfel", "Apfel").sorted(locale: "de"), ("Apfel", "Äpfel", "Zebra"))
+#test(("Zebra", "Äpfel", "Apfel").sorted(locale: "sv"), ("Apfel", "Zebra", "Äpfel"))
+#test((3, 1, 2).sorted(locale: "de"), (1, 2, 3))
+
+--- array-sorted-locale-invalid ---
+// Error: 28-33 invalid locale `d e`
+#("b", "a").sorted(locale: "d e")
+
 --- array-sorted-key-function-positional-1 ---
 // Error: 12-18 unexpected argument
 #().sorted(x => x)
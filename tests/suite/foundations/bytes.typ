@@ -29,3 +29,13 @@
 --- bytes-bad-conversion-from-dict ---
 // Error: 8-14 expected string, array, or bytes, found dictionary
 #bytes((a: 1))
+
+--- bytes-hash ---
+#test(bytes("typst").hash(), "aceb8d6389f7a425854fdfd5de310c10ec025ddcf4bd3fc06736adccfdcbddcf")
+#test(bytes("typst").hash(algorithm: "md5"), "29bcfb9f9ab3c0667d3b7ede8621e902")
+#test(bytes("typst").hash(algorithm: "sha1"), "497cec24447e8e87750c59d55709ba0d78dd9c1b")
+#test(bytes((1, 2, 3)).hash(), "039058c6f2c0cb492c533b0a4d14ef77cc0f78abccced5287d84a1a2011cfb81")
+
+--- bytes-hash-bad-algorithm ---
+// Error: 29-36 expected "md5", "sha1", or "sha256"
+#bytes("a").hash(algorithm: "crc32")
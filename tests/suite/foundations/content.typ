@@ -130,3 +130,16 @@
 --- content-try-to-access-internal-field ---
 // Error: 9-15 hide does not have field "hidden"
 #hide[].hidden
+
+--- content-plain-text ---
+#test([*Hello* _world!_].plain-text(), "Hello world!")
+#test([#image("/assets/images/tiger.jpg")].plain-text(), "")
+#test([$x^2 + 1/2$].plain-text(), "x^2 + 1/2")
+#test([$sqrt(a + b)$].plain-text(), "√(a + b)")
+
+--- content-plain-text-nested ---
+// Elements with a plain-text fallback must also cover their children, without
+// the traversal repeating them.
+#test([*$x^(a/b)$* and _$sqrt(1/2)$_].plain-text(), "x^(a/b) and √(1/2)")
+#test([$attach(x, t: a, tr: b, b: c, br: d)$].plain-text(), "x^a_c^b_d")
+#test([$root(3, x^2)$].plain-text(), "3√(x^2)")
@@ -0,0 +1,6 @@
+--- debug ---
+// Warning: 2-24 debug: sum is 3
+#debug("sum is", 1 + 2)
+
+// Warning: 2-17 debug: (1, "a")
+#debug((1, "a"))
@@ -101,3 +101,16 @@
 #test(duration(minutes: 20) < duration(minutes: 10), false)
 #test(duration(minutes: 20) <= duration(minutes: 10), false)
 #test(duration(minutes: 20) == duration(minutes: 10), false)
+
+--- duration-display ---
+#let d = duration(days: 1, hours: 2, minutes: 3, seconds: 4)
+#test(d.display(), "26:03:04")
+#test(d.display("[days]d [hours]:[minutes]:[seconds]"), "1d 02:03:04")
+#test(d.display("[minutes] min"), "1563 min")
+#test(d.display("[weeks] weeks, [days] days"), "0 weeks, 1 days")
+#test((-d).display("[hours]h [minutes]m"), "\u{2212}26h 03m")
+#test(duration(seconds: 5).display(), "0:00:05")
+
+--- duration-display-unknown-placeholder ---
+// Error: 2-38 unknown duration placeholder `[years]`
+#duration(days: 1).display("[years]")
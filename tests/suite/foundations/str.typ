@@ -65,6 +65,33 @@
 // Error: 18-19 base is only supported for integers
 #str(1.23, base: 2)
 
+--- str-constructor-digits ---
+#test(str(3.14159, digits: 2), "3.14")
+#test(str(2.5, digits: 3), "2.500")
+#test(str(-1.005, digits: 0), "−1")
+#test(str(-0.001, digits: 2), "0.00")
+#test(str(42, digits: 2), "42.00")
+#test(str(42, digits: 0), "42")
+#test(str(2.5, digits: auto), "2.5")
+#test(str(decimal("1.005"), digits: 2), "1.01")
+#test(str(decimal("-0.001"), digits: 2), "0.00")
+#test(str(decimal("2.5"), digits: 3), "2.500")
+#test(str(decimal("2.50")), "2.50")
+
+--- str-constructor-digits-unsupported ---
+// Error: 23-24 digits are only supported for numbers
+#str("hello", digits: 2)
+
+--- str-pad ---
+#test("7".pad(3, fill: "0"), "007")
+#test("1234".pad(3, fill: "0"), "1234")
+#test("ab".pad(4), "  ab")
+#test("Total".pad(8, fill: ".", at: end), "Total...")
+
+--- str-pad-bad-fill ---
+// Error: 2-24 fill must be exactly one grapheme cluster
+#"a".pad(3, fill: "ab")
+
 --- str-from-and-to-unicode ---
 // Test the unicode function.
 #test(str.from-unicode(97), "a")
@@ -328,6 +355,19 @@
 #test("abc".rev(), "cba")
 #test("ax̂e".rev(), "ex̂a")
 
+--- str-uuid ---
+#test(str.uuid("https://typst.app"), "13ef7a50-a42e-5c0a-8701-9608aa367e56")
+#test(str.uuid("typst.app", namespace: "dns"), "c0a76bcb-17fe-52f1-8665-523f66152570")
+#test(str.uuid(bytes("typst.app"), namespace: "dns"), str.uuid("typst.app", namespace: "dns"))
+#test(
+  str.uuid("typst.app", namespace: "6ba7b810-9dad-11d1-80b4-00c04fd430c8"),
+  str.uuid("typst.app", namespace: "dns"),
+)
+
+--- str-uuid-bad-namespace ---
+// Error: 27-33 expected predefined namespace or UUID
+#str.uuid("a", namespace: "nope")
+
 --- string-unclosed ---
 // Error: 2-2:1 unclosed string
 #"hello\"
@@ -0,0 +1,29 @@
+// Test the metadata element.
+
+--- metadata-query-label ---
+// Metadata can be found through its label.
+#metadata("This is a note") <note>
+#context test(query(<note>).first().value, "This is a note")
+
+--- metadata-query-structured ---
+// Metadata can hold arbitrary structured values.
+#let answer(question, value) = [#metadata((question: question, answer: value)) <answer>]
+#answer(1, "B")
+#answer(2, "D")
+#context {
+  let answers = query(<answer>).map(it => it.value)
+  test(answers.len(), 2)
+  test(answers.map(it => it.answer), ("B", "D"))
+}
+
+--- metadata-query-where ---
+// Metadata can be selected by value.
+#metadata(1)
+#metadata(2)
+#metadata(2)
+#context test(query(metadata.where(value: 2)).len(), 2)
+
+--- metadata-invisible ---
+// Metadata produces no visible output, but is still located.
+#metadata("hidden") <hidden>
+#context test(locate(<hidden>).page(), 1)
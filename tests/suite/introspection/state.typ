@@ -49,8 +49,9 @@ Was: #context {
 
 --- state-no-convergence ---
 // Make sure that a warning is produced if the layout fails to converge.
-// Warning: layout did not converge within 5 attempts
-// Hint: check if any states or queries are updating themselves
+// Warning: 2:10-2:33 layout did not converge within 5 attempts
+// Hint: 2:10-2:33 this may be caused by the `state-update` element changing between layout passes
+// Hint: 2:10-2:33 check if any states or queries are updating themselves
 #let s = state("s", 1)
 #context s.update(s.final() + 1)
 #context s.get()
@@ -124,6 +124,17 @@ To the right! Where the sunlight peeks behind the mountain.
 #test((bottom + end).inv(), (start + top))
 #test((horizon + center).inv(), (center + horizon))
 
+--- alignment-to-absolute ---
+#context test(start.to-absolute(), left)
+#context test(end.to-absolute(), right)
+#context test((end + top).to-absolute(), right + top)
+#context test(center.to-absolute(), center)
+#context test(bottom.to-absolute(), bottom)
+#set text(dir: rtl)
+#context test(start.to-absolute(), right)
+#context test((end + horizon).to-absolute(), left + horizon)
+#context test(left.to-absolute(), left)
+
 --- alignment-add-two-horizontal ---
 // Error: 8-22 cannot add two horizontal alignments
 #align(center + right, [A])
@@ -220,6 +220,24 @@ First!
   is the sun.
 ]
 
+--- block-open-edges-multiple-pages ---
+// Test open edges and clipping of a block broken across pages.
+#set page(width: 80pt, height: 60pt, margin: 5pt)
+
+#block(
+  width: 100%,
+  fill: aqua,
+  stroke: 1pt + black,
+  radius: 6pt,
+  inset: 4pt,
+  clip: true,
+  open-edges: true,
+)[
+  #for _ in range(3) {
+    rect(width: 100%, height: 20pt, fill: eastern)
+  }
+]
+
 --- box-clip-radius ---
 // Test clipping with `radius`.
 #set page(height: 60pt)
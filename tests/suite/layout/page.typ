@@ -74,6 +74,33 @@
 #set page(fill: none)
 #rect(fill: green)
 
+--- page-bleed ---
+// Test that the background extends into the bleed area.
+#set page(
+  width: 60pt,
+  height: 40pt,
+  margin: 5pt,
+  bleed: 6pt,
+  background: rect(width: 100%, height: 100%, fill: eastern),
+)
+Bleed
+
+--- page-bleed-position ---
+// Test that the bleed doesn't affect positions on the page.
+#set page(width: 60pt, height: 40pt, margin: 5pt, bleed: 6pt, crop-marks: true)
+#context test(here().position(), (page: 1, x: 5pt, y: 5pt))
+#place(bottom + right)[#context test(here().position().x, 55pt)]
+
+--- page-bleed-crop-marks ---
+// Test crop marks outside of the bleed area.
+#set page(width: 60pt, height: 40pt, margin: 5pt, bleed: 6pt, crop-marks: true)
+Crop
+
+--- page-crop-marks-without-bleed ---
+// Crop marks have no effect without bleed.
+#set page(width: 60pt, height: 40pt, margin: 5pt, crop-marks: true)
+Crop
+
 --- page-margin-uniform ---
 // Set all margins at once.
 #[
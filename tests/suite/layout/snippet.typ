@@ -0,0 +1,17 @@
+// Test compiling a document as a tightly sized snippet. The snippet's size and
+// errors are checked in custom.rs.
+
+--- snippet-size ---
+#rect(width: 20pt, height: 10pt)
+
+--- snippet-introspection ---
+// Introspections converge like in a regular document.
+#{
+  counter("a").update(3)
+  context rect(width: counter("a").final().first() * 10pt)
+}
+
+--- snippet-page-config ---
+// Pages cannot be configured in a snippet.
+#set page(width: 100pt)
+#rect()
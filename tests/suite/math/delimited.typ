@@ -101,3 +101,12 @@ $lr(body2, size: #50%)$
 --- issue-4188-lr-corner-brackets ---
 // Test positioning of U+231C to U+231F
 $⌜a⌟⌞b⌝$ = $⌜$$a$$⌟$$⌞$$b$$⌝$
+
+--- math-lr-dirac ---
+// Test Dirac notation.
+$ bra(psi) quad ket(phi) quad braket(psi, phi) quad expval(A) $
+$ braket(psi, A, phi) = braket(1/2 psi, H, phi^2) $
+
+--- math-lr-dirac-size ---
+// Test the size of the brackets in Dirac notation.
+$ ket(x/2, size: #200%) quad braket(a, b, size: #150%) $
@@ -260,6 +260,36 @@ $ q &= sum_k ln A \
 // Test numbering on empty equations.
 #math.equation(numbering: "1", block: true, [])
 
+--- math-equation-tag ---
+// A tagged equation shows its tag and doesn't step the counter, so the next
+// untagged equation continues the numbering.
+#set page(width: 150pt)
+#set math.equation(numbering: "(1)")
+$ a + b = c $ <first>
+#math.equation(block: true, tag: [(1a)], $ a = c - b $) <tagged>
+$ c - a = b $ <second>
+See @first, @tagged, and @second.
+#context test(counter(math.equation).at(<second>), (2,))
+
+--- math-equation-tag-without-numbering ---
+// A tagged equation can be referenced even without numbering.
+#math.equation(block: true, tag: [($star$)], $ x = y $) <star>
+#context test(counter(math.equation).final(), (0,))
+From @star.
+
+--- math-equation-tag-set-rule ---
+// A tag from a set rule is respected by the counter and by references.
+#set page(width: 150pt)
+#set math.equation(numbering: "(1)")
+#[
+  #set math.equation(tag: [(A)])
+  $ x = y $ <set>
+]
+$ y = z $ <after>
+See @set and @after.
+#context test(counter(math.equation).at(<after>), (1,))
+#context test(query(<set>).first().tag, [(A)])
+
 --- issue-4187-alignment-point-affects-row-height ---
 // In this bug, a row of "-" only should have a very small height; but
 // after adding an alignment point "&", the row gains a larger height.
@@ -41,3 +41,26 @@ $ a_1/b_2, 1/f(x), zeta(x)/2, "foo"[|x|]/2 \
   +[x]/2, 1(x)/2, 2[x]/2 \
   (a)b/2, b(a)[b]/2 \
   n!/2, 5!/2, n !/2, 1/n!, 1/5! $
+
+--- math-frac-style-skewed ---
+// Test skewed fractions.
+$ frac(a, b, style: "skewed") = frac(x^2, y_1, style: "skewed") $
+$x = frac(1, 2, style: "skewed")$
+
+--- math-frac-style-horizontal ---
+// Test horizontal fractions.
+$ frac(a + b, c, style: "horizontal") = frac(x, frac(1, 2), style: "horizontal") $
+$x = frac(1, 2, style: "horizontal")$
+
+// Grouping parentheses removed by the parser are restored.
+#set math.frac(style: "horizontal")
+$ (a+b)/c = a/(b c) $
+
+--- math-frac-style-set-rule ---
+// Test setting the fraction style for a whole equation.
+#set math.frac(style: "skewed")
+$ 1/2 + a/(b + c) $
+
+--- math-frac-style-invalid ---
+// Error: 21-25 expected "vertical", "skewed", or "horizontal"
+$ frac(a, b, style: "up") $
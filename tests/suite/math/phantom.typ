@@ -0,0 +1,14 @@
+// Test phantoms.
+
+--- math-phantom ---
+$ a + b + c \
+  phantom(a +) b + c $
+$ sqrt(phantom(b, horizontal: #false) x) + sqrt(y) $
+$ x phantom(y, vertical: #false) z $
+
+--- math-phantom-introspection ---
+// Labels and counters within a phantom still work.
+#let c = counter("c")
+$ phantom(#[#c.step() #metadata(none) <hidden>] a) x $
+#context test(c.get(), (1,))
+#context test(query(<hidden>).len(), 1)
@@ -52,3 +52,25 @@ Hello
   set document(author: "Changed") if "Normal" in document.author
   set document(title: "Changed") if document.title ==  "Normal"
 }
+
+--- document-count-text-sections ---
+// Text is counted per heading section, across pages. Checked in custom.rs.
+Not counted
+= One
+Two three
+#pagebreak()
+Four
+= Five
+Six
+
+--- document-count-text-hyphenated ---
+// A word hyphenated across lines counts once. Checked in custom.rs.
+#set page(width: 60pt)
+#set text(hyphenate: true)
+= A
+An extraordinarily long word
+
+--- document-count-text-sub-super ---
+// Sub- and superscripts belong to their word. Checked in custom.rs.
+= A
+H#sub(typographic: false)[2]O and x#super(typographic: false)[2]
@@ -62,6 +62,27 @@ a + 0.
 + First
   + Nested
 
+--- enum-reversed ---
+// Test that reversed enumerations count downwards from the number of items.
+#enum(
+  reversed: true,
+  numbering: n => metadata(n),
+  spacing: 0pt,
+  [], [], [],
+)
+#context test(query(metadata).map(m => m.value), (3, 2, 1))
+
+--- enum-reversed-start ---
+// Test that reversed enumerations end with the start number.
+#enum(
+  reversed: true,
+  start: 10,
+  numbering: n => metadata(n),
+  spacing: 0pt,
+  [], [], [],
+)
+#context test(query(metadata).map(m => m.value), (12, 11, 10))
+
 --- enum-numbering-closure ---
 // Test numbering with closure.
 #enum(
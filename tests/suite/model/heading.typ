@@ -35,6 +35,17 @@ multiline.
 = This
   is not.
 
+--- heading-block-spacing ---
+// Headings have more space above than below them by default.
+#show heading: it => context test(block.above > block.below, true)
+= Heading
+== Subheading
+
+// This can be overridden per heading level.
+#show heading.where(level: 2): set block(above: 0.5em, below: 1em)
+#show heading.where(level: 2): it => context test(block.above < block.below, true)
+== Subheading
+
 --- heading-show-where ---
 // Test styling.
 #show heading.where(level: 5): it => block(
@@ -78,3 +89,29 @@ Not in heading
 // Error: 1:19-1:25 cannot reference heading without numbering
 // Hint: 1:19-1:25 you can enable heading numbering with `#set heading(numbering: "1.")`
 Cannot be used as @intro
+
+--- heading-current-page ---
+// A heading on the current page wins over earlier ones, even if it comes
+// later on the page.
+#set page(height: 50pt)
+= First
+#context test(heading.current().body, [First])
+#pagebreak()
+#context test(heading.current().body, [First])
+#pagebreak()
+#context test(heading.current().body, [Third])
+= Third
+
+--- heading-current-level ---
+// A heading with a smaller level ends the section of a deeper one.
+#set page(height: 50pt)
+= Chapter
+== Section
+#context test(heading.current(level: 2).body, [Section])
+#pagebreak()
+= Next
+#context test(heading.current().body, [Next])
+#context test(heading.current(level: 2), none)
+
+--- heading-current-none ---
+#context test(heading.current(), none)
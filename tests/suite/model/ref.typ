@@ -47,6 +47,23 @@ $ A = 1 $ <eq2>
 #set ref(supplement: none)
 @fig1, @fig2, @eq1, @eq2
 
+--- ref-supplement-show-rule ---
+// Test that show rules can access the resolved supplement.
+#figure[] <fig>
+#show ref: it => {
+  if it.element != none {
+    test(it.supplement, [Figure])
+  }
+}
+@fig
+#set ref(supplement: it => [Fig.])
+#show ref: it => {
+  if it.element != none {
+    test(it.supplement, [Fig.])
+  }
+}
+@fig
+
 --- ref-ambiguous ---
 // Test ambiguous reference.
 = Introduction <arrgh>
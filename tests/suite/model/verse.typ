@@ -0,0 +1,30 @@
+// Test verses.
+
+--- verse-string ---
+// Line breaks, indentation, and stanzas are kept from a string.
+#verse("Tyger Tyger, burning bright,
+In the forests of the night;
+  What immortal hand or eye,
+	Could frame thy fearful symmetry?
+
+In what distant deeps or skies.
+Burnt the fire of thine eyes?")
+
+--- verse-content ---
+// Lines end at line breaks and stanzas at paragraph breaks, while other
+// content stays within its line.
+#verse[
+  _Tyger Tyger_, burning bright, \
+  In the *forests* of the night;
+
+  What immortal hand or eye, \
+  Could frame thy fearful symmetry?
+]
+
+--- verse-hanging-indent-and-tab-size ---
+#set page(width: 100pt)
+#verse(
+  hanging-indent: 2em,
+  tab-size: 2,
+  "A line that is too long to fit onto the page\n\tA short line",
+)
@@ -15,6 +15,17 @@
 #set text(font: "Noto Serif CJK TC", lang: "zh", region: "TW")
 #outline()
 
+--- text-lang-region-supplement ---
+// The region can refine localized supplements.
+#figure[] <fig>
+#context test(query(<fig>).first().supplement, [Figure])
+#set text(lang: "fr")
+#figure[] <fig-fr>
+#context test(query(<fig-fr>).first().supplement, [Fig.])
+#set text(lang: "fr", region: "CA")
+#figure[] <fig-ca>
+#context test(query(<fig-ca>).first().supplement, [Figure])
+
 --- text-lang-hyphenate ---
 // Ensure that setting the language does have effects.
 #set text(hyphenate: true)
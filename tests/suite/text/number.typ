@@ -0,0 +1,46 @@
+--- text-num-english ---
+#context {
+  test(text.num(1234567.891), "1,234,567.891")
+  test(text.num(1234, digits: 2), "1,234.00")
+  test(text.num(-999.5), "\u{2212}999.5")
+  test(text.num(0.126, digits: 2), "0.13")
+}
+
+--- text-num-decimal ---
+#context {
+  test(text.num(decimal("1234567.891")), "1,234,567.891")
+  test(text.num(decimal("0.125"), digits: 2), "0.13")
+  test(text.num(decimal("-1234.5"), digits: 3), "\u{2212}1,234.500")
+  test(text.num(decimal("12345678901234567890.1")), "12,345,678,901,234,567,890.1")
+}
+
+--- text-num-lang ---
+#set text(lang: "de")
+#context test(text.num(1234567.5), "1.234.567,5")
+#set text(region: "ch")
+#context test(text.num(1234567.5), "1’234’567.5")
+#set text(lang: "fr", region: none)
+#context test(text.num(1234567.5), "1\u{202f}234\u{202f}567,5")
+#set text(lang: "es")
+#context test(text.num(1234), "1234")
+#context test(text.num(12345), "12.345")
+
+--- text-money-region ---
+#set text(lang: "en", region: "us")
+#context test(text.money(1499.5), "$1,499.50")
+#context test(text.money(-20), "\u{2212}$20.00")
+#context test(text.money(decimal("0.105")), "$0.11")
+#context test(text.money(1499.5, currency: "chf"), "CHF\u{a0}1,499.50")
+#set text(lang: "de", region: "de")
+#context test(text.money(1499.5), "1.499,50\u{a0}€")
+#set text(lang: "ja", region: "jp")
+#context test(text.money(1499.6), "¥1,500")
+
+--- text-money-no-region ---
+// Error: 10-23 cannot determine currency from the text region
+// Hint: 10-23 specify the currency explicitly, e.g. `currency: "EUR"`
+#context text.money(5)
+
+--- text-money-bad-currency ---
+// Error: 10-41 expected three-letter currency code
+#context text.money(5, currency: "euro")
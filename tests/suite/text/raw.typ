@@ -4,6 +4,11 @@
 // Empty raw block.
 Empty raw block:``.
 
+--- raw-ligatures-disabled ---
+// Raw text disables standard ligatures by default.
+#show raw: it => context test(text.ligatures, false)
+`fi`
+
 --- raw-consecutive-single-backticks ---
 // No extra space.
 `A``B`
@@ -0,0 +1,36 @@
+// Test arcs.
+
+--- arc-kinds ---
+// Test the three kinds of arcs.
+#set arc(radius: 10pt, start: -45deg, stop: 200deg)
+#arc(kind: "open")
+#arc(kind: "chord")
+#arc(kind: "pie", fill: eastern, stroke: 1pt + black)
+
+--- arc-direction ---
+// Test arcs drawn counter-clockwise and arcs longer than a circle.
+#set arc(radius: 8pt)
+#stack(
+  dir: ltr,
+  spacing: 4pt,
+  arc(start: 90deg, stop: -90deg, stroke: red),
+  arc(start: 0deg, stop: 720deg, stroke: blue),
+  arc(start: 30deg, stop: 30deg),
+)
+
+--- arc-pie-chart ---
+// Test placing multiple arcs on top of each other.
+#set arc(radius: 12pt, kind: "pie", stroke: white)
+#box({
+  place(arc(stop: 120deg, fill: red))
+  place(arc(start: 120deg, stop: 200deg, fill: green))
+  arc(start: 200deg, stop: 360deg, fill: blue)
+})
+
+--- arc-kind-invalid ---
+// Error: 12-19 expected "open", "chord", or "pie"
+#arc(kind: "wedge")
+
+--- arc-radius-negative ---
+// Error: 2-19 arc radius must not be negative
+#arc(radius: -5pt)
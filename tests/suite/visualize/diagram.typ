@@ -0,0 +1,54 @@
+--- diagram-nodes ---
+// Test node placement in a grid.
+#diagram(
+  diagram.node((0, 0))[A],
+  diagram.node((2, 0))[Long],
+  diagram.node((1, 1), $x^2$),
+)
+
+--- diagram-edges ---
+// Test edges with labels, double lines, and without heads.
+#diagram(
+  diagram.node((0, 0), $A$),
+  diagram.node((1, 0), $B$),
+  diagram.node((0, 1), $C$),
+  diagram.node((1, 1), $D$),
+  diagram.edge((0, 0), (1, 0), $f$),
+  diagram.edge((0, 0), (0, 1), $g$, label-side: right),
+  diagram.edge((1, 0), (1, 1), head: false),
+  diagram.edge((0, 1), (1, 1), double: true),
+)
+
+--- diagram-edge-bend ---
+// Test bent edges in both directions. Bent edges may extend beyond the
+// diagram's bounds.
+#pad(y: 12pt, diagram(
+  stroke: blue,
+  diagram.node((0, 0), $A$),
+  diagram.node((1, 0), $B$),
+  diagram.node((2, 0), $C$),
+  diagram.edge((0, 0), (1, 0), $f$, bend: 30deg),
+  diagram.edge((1, 0), (0, 0), $g$, bend: 30deg),
+  diagram.edge((1, 0), (2, 0), bend: -60deg, double: true),
+))
+
+--- diagram-edge-bend-invalid ---
+// Error: 35-76 bend must be between -90deg and 90deg
+#diagram(diagram.node((0, 0))[A], diagram.edge((0, 0), (1, 0), bend: 90deg))
+
+--- diagram-edge-label-side-invalid ---
+// Error: 51-54 expected either `left` or `right`
+#diagram(diagram.edge((0, 0), (1, 0), label-side: top))
+
+--- diagram-non-node-child ---
+// Error: 10-13 expected `diagram.node` or `diagram.edge`
+// Hint: 10-13 wrap other content in `diagram.node` to place it
+#diagram([A])
+
+--- diagram-edge-loop ---
+// Error: 35-63 cannot draw an edge from a cell to itself
+#diagram(diagram.node((0, 0))[A], diagram.edge((0, 0), (0, 0)))
+
+--- diagram-bad-position ---
+// Error: 23-32 position array must contain exactly two entries
+#diagram(diagram.node((0, 0, 1))[A])
@@ -103,6 +103,14 @@ A #box(image("/assets/images/tiger.jpg", height: 1cm, width: 80%)) B
 // Error: 2-91 failed to decode image (Format error decoding Png: Invalid PNG signature.)
 #image.decode(read("/assets/images/tiger.jpg", encoding: none), format: "png", width: 80%)
 
+--- image-placeholder ---
+// Test that placeholders are gray boxes of the given size.
+#let placeholder = image.placeholder(width: 2cm, height: 1cm)[Logo]
+#test(placeholder.func(), rect)
+#test(placeholder.width, 2cm)
+#test(placeholder.height, 1cm)
+#test(image.placeholder().width, 100%)
+
 --- issue-870-image-rotation ---
 // Ensure that EXIF rotation is applied.
 // https://github.com/image-rs/image/issues/1045
@@ -116,6 +124,17 @@ A #box(image("/assets/images/tiger.jpg", height: 1cm, width: 80%)) B
   test(size, (width: 1024pt, height: 670pt))
 }
 
+--- image-dpi ---
+// Test that the resolution can be overridden.
+#context {
+  let size = measure(image("/assets/images/tiger.jpg", dpi: 144))
+  test(size, (width: 512pt, height: 335pt))
+}
+
+--- image-dpi-invalid ---
+// Error: 2-43 dpi must be positive
+#image("/assets/images/tiger.jpg", dpi: 0)
+
 --- issue-2051-new-cm-svg ---
 #set text(font: "New Computer Modern")
 #image("/assets/images/diagram.svg")